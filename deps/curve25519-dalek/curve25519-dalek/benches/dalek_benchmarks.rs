@@ -36,6 +36,21 @@ mod edwards_benches {
         });
     }
 
+    fn equality<M: Measurement>(c: &mut BenchmarkGroup<M>) {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        // The same point with a different Z, and a different point
+        let P = (B + B) - B;
+        let Q = B + B;
+        c.bench_function("EdwardsPoint equality, equal", move |b| b.iter(|| B == P));
+        c.bench_function("EdwardsPoint eq_vartime, equal", move |b| {
+            b.iter(|| B.eq_vartime(&P))
+        });
+        c.bench_function("EdwardsPoint equality, unequal", move |b| b.iter(|| B == Q));
+        c.bench_function("EdwardsPoint eq_vartime, unequal", move |b| {
+            b.iter(|| B.eq_vartime(&Q))
+        });
+    }
+
     fn consttime_variable_base_scalar_mul<M: Measurement>(c: &mut BenchmarkGroup<M>) {
         let B = &constants::ED25519_BASEPOINT_POINT;
         let s = Scalar::from(897987897u64).invert();
@@ -64,6 +79,7 @@ mod edwards_benches {
         decompress(&mut g);
         consttime_fixed_base_scalar_mul(&mut g);
         consttime_variable_base_scalar_mul(&mut g);
+        equality(&mut g);
         vartime_double_base_scalar_mul(&mut g);
     }
 }
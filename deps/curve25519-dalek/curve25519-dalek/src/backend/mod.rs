@@ -236,6 +236,13 @@ pub fn variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint
     }
 }
 
+/// Perform variable-time, variable-base scalar multiplication.
+pub fn vartime_variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
+    // There is no vectorized implementation: with a single base point
+    // there is little to parallelize.
+    serial::scalar_mul::variable_base::vartime_mul(point, scalar)
+}
+
 /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
 #[allow(non_snake_case)]
 pub fn vartime_double_base_mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
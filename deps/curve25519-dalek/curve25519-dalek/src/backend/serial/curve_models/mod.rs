@@ -132,8 +132,6 @@ use subtle::ConditionallySelectable;
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
-use crate::constants;
-
 use crate::edwards::EdwardsPoint;
 use crate::field::FieldElement;
 use crate::traits::ValidityCheck;
@@ -282,7 +280,7 @@ impl ValidityCheck for ProjectivePoint {
         let ZZ = self.Z.square();
         let ZZZZ = ZZ.square();
         let lhs = &(&YY - &XX) * &ZZ;
-        let rhs = &ZZZZ + &(&constants::EDWARDS_D * &(&XX * &YY));
+        let rhs = &ZZZZ + &(&XX * &YY).mul_by_d();
 
         lhs == rhs
     }
@@ -334,6 +332,11 @@ impl ProjectivePoint {
     /// Convert this point from the \\( \mathbb P\^2 \\) model to the
     /// \\( \mathbb P\^3 \\) model.
     ///
+    /// The point \\((X:Y:Z)\\) is rescaled by \\(Z\\) to \\((XZ:YZ:Z\^2)\\),
+    /// which represents the same affine point, and \\(T = XY\\) then
+    /// satisfies the extended invariant
+    /// \\( XZ \cdot YZ = Z\^2 \cdot XY \\) without any inversion.
+    ///
     /// This costs \\(3 \mathrm M + 1 \mathrm S\\).
     pub fn as_extended(&self) -> EdwardsPoint {
         EdwardsPoint {
@@ -418,7 +421,7 @@ impl<'a, 'b> Add<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
         let MM = &Y_minus_X * &other.Y_minus_X;
         let TT2d = &self.T * &other.T2d;
         let ZZ = &self.Z * &other.Z;
-        let ZZ2 = &ZZ + &ZZ;
+        let ZZ2 = ZZ.double();
 
         CompletedPoint {
             X: &PP - &MM,
@@ -440,7 +443,7 @@ impl<'a, 'b> Sub<&'b ProjectiveNielsPoint> for &'a EdwardsPoint {
         let MP = &Y_minus_X * &other.Y_plus_X;
         let TT2d = &self.T * &other.T2d;
         let ZZ = &self.Z * &other.Z;
-        let ZZ2 = &ZZ + &ZZ;
+        let ZZ2 = ZZ.double();
 
         CompletedPoint {
             X: &PM - &MP,
@@ -461,7 +464,7 @@ impl<'a, 'b> Add<&'b AffineNielsPoint> for &'a EdwardsPoint {
         let PP = &Y_plus_X * &other.y_plus_x;
         let MM = &Y_minus_X * &other.y_minus_x;
         let Txy2d = &self.T * &other.xy2d;
-        let Z2 = &self.Z + &self.Z;
+        let Z2 = self.Z.double();
 
         CompletedPoint {
             X: &PP - &MM,
@@ -482,7 +485,7 @@ impl<'a, 'b> Sub<&'b AffineNielsPoint> for &'a EdwardsPoint {
         let PM = &Y_plus_X * &other.y_minus_x;
         let MP = &Y_minus_X * &other.y_plus_x;
         let Txy2d = &self.T * &other.xy2d;
-        let Z2 = &self.Z + &self.Z;
+        let Z2 = self.Z.double();
 
         CompletedPoint {
             X: &PM - &MP,
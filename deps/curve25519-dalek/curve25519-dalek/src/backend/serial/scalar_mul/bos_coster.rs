@@ -0,0 +1,203 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Implementation of the Bos–Coster method for multiscalar multiplication.
+
+#![allow(non_snake_case)]
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+use crate::edwards::EdwardsPoint;
+use crate::scalar::Scalar;
+use crate::traits::Identity;
+use crate::traits::VartimeMultiscalarMul;
+
+/// When the largest scalar has this many more bits than the next
+/// largest one, the subtractive steps are abandoned and the term is
+/// computed directly with a variable-base multiplication.
+const MAX_BIT_GAP: u32 = 4;
+
+/// A single term \\(aP\\) of the sum, ordered by the integer value of \\(a\\).
+struct Term {
+    /// The scalar \\(a\\), as little-endian 64-bit limbs.
+    scalar: [u64; 4],
+    point: EdwardsPoint,
+}
+
+impl Term {
+    fn new(scalar: &Scalar, point: EdwardsPoint) -> Term {
+        Term {
+            scalar: scalar.to_limbs(),
+            point,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.scalar == [0u64; 4]
+    }
+
+    /// The number of bits needed to represent the scalar.
+    fn bits(&self) -> u32 {
+        match self.scalar.iter().rposition(|&limb| limb != 0) {
+            Some(i) => 64 * (i as u32) + (64 - self.scalar[i].leading_zeros()),
+            None => 0,
+        }
+    }
+
+    /// Replace the scalar \\(a\\) with \\(a - b\\).  Requires \\(a \geq b\\).
+    fn sub_scalar(&mut self, b: &[u64; 4]) {
+        let mut borrow = false;
+        for (a, b) in self.scalar.iter_mut().zip(b.iter()) {
+            let (d1, o1) = a.overflowing_sub(*b);
+            let (d2, o2) = d1.overflowing_sub(borrow as u64);
+            *a = d2;
+            borrow = o1 | o2;
+        }
+        debug_assert!(!borrow);
+    }
+
+    /// Compute \\(aP\\) directly.
+    fn mul(&self) -> EdwardsPoint {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(self.scalar.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        // The scalar only ever decreases, so it still satisfies
+        // invariant #1 if the input did.
+        Scalar { bytes } * self.point
+    }
+}
+
+impl PartialEq for Term {
+    fn eq(&self, other: &Term) -> bool {
+        self.scalar == other.scalar
+    }
+}
+
+impl Eq for Term {}
+
+impl PartialOrd for Term {
+    fn partial_cmp(&self, other: &Term) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Term {
+    fn cmp(&self, other: &Term) -> Ordering {
+        self.scalar.iter().rev().cmp(other.scalar.iter().rev())
+    }
+}
+
+/// Perform multiscalar multiplication using the Bos–Coster method.
+///
+/// The terms \\(a\_i P\_i\\) are kept in a max-heap ordered by \\(a\_i\\).
+/// At each step the two largest terms \\(aP\\) and \\(bQ\\), with
+/// \\(a \geq b\\), are replaced using the identity
+/// $$
+///     aP + bQ = (a - b)P + b(P + Q),
+/// $$
+/// which preserves the total sum while shrinking the scalars, at the cost
+/// of a single point addition.  Terms whose scalar reaches zero are
+/// dropped.  If the largest scalar is much bigger than the next one, the
+/// subtractive steps would take too long, so that term is instead
+/// computed directly and removed from the heap.
+///
+/// This is only efficient when the scalars are of similar sizes, which is
+/// the case for random scalars.  Since the sequence of operations depends
+/// on the scalars, this is a variable-time algorithm.
+pub struct BosCoster;
+
+impl VartimeMultiscalarMul for BosCoster {
+    type Point = EdwardsPoint;
+
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        let terms = scalars
+            .into_iter()
+            .zip(points)
+            .map(|(s, maybe_p)| maybe_p.map(|p| Term::new(s.borrow(), p)))
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut heap: BinaryHeap<Term> = terms.into_iter().filter(|t| !t.is_zero()).collect();
+        let mut result = EdwardsPoint::identity();
+
+        while let Some(mut a) = heap.pop() {
+            let mut b = match heap.pop() {
+                Some(b) => b,
+                None => {
+                    result += a.mul();
+                    break;
+                }
+            };
+
+            if a.bits() > b.bits() + MAX_BIT_GAP {
+                result += a.mul();
+                heap.push(b);
+                continue;
+            }
+
+            // aP + bQ = (a - b)P + b(P + Q)
+            b.point += a.point;
+            a.sub_scalar(&b.scalar);
+
+            heap.push(b);
+            if !a.is_zero() {
+                heap.push(a);
+            }
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn test_vartime_bos_coster() {
+        let mut rng = rand::thread_rng();
+
+        for n in [1, 2, 3, 16, 64] {
+            let points: Vec<EdwardsPoint> = (0..n)
+                .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+                .collect();
+            let mut scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+            // Include a zero and a tiny scalar, which are far from the others.
+            if n > 2 {
+                scalars[0] = Scalar::ZERO;
+                scalars[1] = Scalar::ONE;
+            }
+
+            let control: EdwardsPoint = scalars.iter().zip(points.iter()).map(|(s, P)| s * P).sum();
+            let subject = BosCoster::vartime_multiscalar_mul(&scalars, &points);
+
+            assert_eq!(subject.compress(), control.compress());
+        }
+    }
+
+    #[test]
+    fn test_vartime_bos_coster_unreduced_torsion() {
+        // Unreduced scalars must be treated as integers, which is only
+        // observable for points with a torsion component.
+        let s = crate::scalar::test::LARGEST_UNREDUCED_SCALAR;
+        let P = constants::ED25519_BASEPOINT_POINT + constants::EIGHT_TORSION[1];
+        let Q = constants::EIGHT_TORSION[3];
+
+        let control = s * P + s * Q;
+        let subject = BosCoster::vartime_multiscalar_mul([s, s], [P, Q]);
+
+        assert_eq!(subject.compress(), control.compress());
+    }
+}
@@ -31,3 +31,7 @@ pub mod precomputed_straus;
 
 #[cfg(feature = "alloc")]
 pub mod pippenger;
+
+// Bos–Coster is slower than Straus and Pippenger, so no public API uses it.
+#[cfg(all(test, feature = "alloc"))]
+pub mod bos_coster;
@@ -153,6 +153,12 @@ impl VartimeMultiscalarMul for Pippenger {
             buckets_sum
         });
 
+        // Writing C_k for the column sums, the fold computes
+        //
+        //    (..((C_{d-1} 2^w + C_{d-2}) 2^w + C_{d-3}) ..) 2^w + C_0 = sum_k C_k 2^(wk),
+        //
+        // where d = digits_count. Since C_k = sum_i s_{i,k} P_i, this is sum_i s_i P_i.
+        //
         // Take the high column as an initial value to avoid wasting time doubling the identity element in `fold()`.
         let hi_column = columns.next().expect("should have more than zero digits");
 
@@ -196,4 +202,51 @@ mod test {
             n /= 2;
         }
     }
+
+    /// Check that combining the columns by shifting them into place
+    /// recovers the full sum, for each window width, by replaying the
+    /// final fold on column sums computed directly from the digits.
+    #[test]
+    fn test_vartime_pippenger_window_combine() {
+        let mut rng = rand::thread_rng();
+        let n = 8;
+        let points: Vec<EdwardsPoint> = (0..n)
+            .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+            .collect();
+        let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+        let control: EdwardsPoint = scalars.iter().zip(points.iter()).map(|(s, P)| s * P).sum();
+
+        for w in 6..=8 {
+            let digits: Vec<_> = scalars.iter().map(|s| s.as_radix_2w(w)).collect();
+            let digits_count = Scalar::to_radix_2w_size_hint(w);
+
+            let mut columns = (0..digits_count).rev().map(|k| {
+                digits
+                    .iter()
+                    .zip(points.iter())
+                    .map(|(d, P)| {
+                        let abs_digit = Scalar::from((d[k] as i16).unsigned_abs() as u64);
+                        let digit = if d[k] < 0 { -abs_digit } else { abs_digit };
+                        digit * P
+                    })
+                    .sum::<EdwardsPoint>()
+            });
+            let hi_column = columns.next().unwrap();
+            let total = columns.fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p);
+
+            assert_eq!(total, control);
+        }
+
+        // Run the real thing at sizes which select each of the widths
+        for n in [100, 600, 800] {
+            let points: Vec<EdwardsPoint> = (0..n)
+                .map(|i| constants::ED25519_BASEPOINT_POINT * Scalar::from(1 + i as u64))
+                .collect();
+            let scalars = vec![-Scalar::ONE; n];
+            let control = -points.iter().sum::<EdwardsPoint>();
+
+            let subject = Pippenger::vartime_multiscalar_mul(&scalars, &points);
+            assert_eq!(subject, control);
+        }
+    }
 }
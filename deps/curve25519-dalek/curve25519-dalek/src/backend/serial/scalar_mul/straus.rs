@@ -125,6 +125,12 @@ impl MultiscalarMul for Straus {
             .map(|s| s.borrow().as_radix_16())
             .collect();
 
+        // After each column j, Q is the sum of the points weighted by the
+        // top digits of their scalars,
+        //
+        //    Q = sum_i (s_{i,j} + s_{i,j+1} 16 + ... + s_{i,63} 16^(63-j)) P_i,
+        //
+        // so after column 0 it is the sum of the s_i P_i.
         let mut Q = EdwardsPoint::identity();
         for j in (0..64).rev() {
             Q = Q.mul_by_pow_2(4);
@@ -156,6 +162,10 @@ impl VartimeMultiscalarMul for Straus {
     /// The non-adjacent form has signed, odd digits.  Using only odd
     /// digits halves the table size (since we only need odd
     /// multiples), or gives fewer additions for the same table size.
+    ///
+    /// Terms with a zero scalar contribute nothing, so they are dropped
+    /// before building the lookup tables.  Their points must still be
+    /// `Some` for the result to be `Some`.
     fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<EdwardsPoint>
     where
         I: IntoIterator,
@@ -168,15 +178,21 @@ impl VartimeMultiscalarMul for Straus {
         use crate::traits::Identity;
         use crate::window::NafLookupTable5;
 
-        let nafs: Vec<_> = scalars
-            .into_iter()
-            .map(|c| c.borrow().non_adjacent_form(5))
-            .collect();
+        let mut nafs = Vec::new();
+        let mut lookup_tables = Vec::new();
+        for (c, P_opt) in scalars.into_iter().zip(points) {
+            let P = P_opt?;
+            let c = c.borrow();
+            if *c == Scalar::ZERO {
+                continue;
+            }
+            nafs.push(c.non_adjacent_form(5));
+            lookup_tables.push(NafLookupTable5::<ProjectiveNielsPoint>::from(&P));
+        }
 
-        let lookup_tables = points
-            .into_iter()
-            .map(|P_opt| P_opt.map(|P| NafLookupTable5::<ProjectiveNielsPoint>::from(&P)))
-            .collect::<Option<Vec<_>>>()?;
+        if nafs.is_empty() {
+            return Some(EdwardsPoint::identity());
+        }
 
         let mut r = ProjectivePoint::identity();
 
@@ -199,3 +215,91 @@ impl VartimeMultiscalarMul for Straus {
         Some(r.as_extended())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::backend::serial::curve_models::ProjectiveNielsPoint;
+    use crate::constants;
+    use crate::traits::{Identity, IsIdentity};
+    use crate::window::LookupTable;
+
+    /// Check that vartime Straus skips zero scalars without changing the
+    /// result, and still rejects missing points for zero scalars.
+    #[test]
+    fn test_vartime_straus_zero_scalars() {
+        let mut rng = rand::thread_rng();
+        let n = 8;
+
+        let points: Vec<EdwardsPoint> = (0..n)
+            .map(|i| {
+                constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng)
+                    + constants::EIGHT_TORSION[i]
+            })
+            .collect();
+        let mut scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+        for i in (0..n).step_by(3) {
+            scalars[i] = Scalar::ZERO;
+        }
+
+        let control: EdwardsPoint = scalars.iter().zip(points.iter()).map(|(s, P)| s * P).sum();
+        let subject = Straus::vartime_multiscalar_mul(&scalars, &points);
+        assert_eq!(subject, control);
+
+        // All zero scalars give the identity
+        let zeros = vec![Scalar::ZERO; n];
+        assert!(Straus::vartime_multiscalar_mul(&zeros, &points).is_identity());
+        assert!(
+            Straus::vartime_multiscalar_mul(&[] as &[Scalar], &[] as &[EdwardsPoint]).is_identity()
+        );
+
+        // A missing point makes the result None even if its scalar is zero
+        let maybe_points = points
+            .iter()
+            .map(|P| Some(*P))
+            .chain(core::iter::once(None));
+        let scalars = scalars.iter().chain(core::iter::once(&Scalar::ZERO));
+        assert!(Straus::optional_multiscalar_mul(scalars, maybe_points).is_none());
+    }
+
+    /// Check the loop invariant of constant-time Straus by replaying its
+    /// column loop: after column j, Q is the sum of h_{i,j} P_i, where
+    /// h_{i,j} is the value of the digits of s_i at positions j and above.
+    #[test]
+    fn test_straus_column_invariant() {
+        let mut rng = rand::thread_rng();
+        let n = 4;
+
+        let points: Vec<EdwardsPoint> = (0..n)
+            .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+            .collect();
+        let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+
+        let lookup_tables: Vec<_> = points
+            .iter()
+            .map(LookupTable::<ProjectiveNielsPoint>::from)
+            .collect();
+        let scalar_digits: Vec<_> = scalars.iter().map(Scalar::as_radix_16).collect();
+
+        let sixteen = Scalar::from(16u64);
+        let mut high = vec![Scalar::ZERO; n];
+        let mut Q = EdwardsPoint::identity();
+        for j in (0..64).rev() {
+            Q = Q.mul_by_pow_2(4);
+            for i in 0..n {
+                Q = (&Q + &lookup_tables[i].select(scalar_digits[i][j])).as_extended();
+
+                let digit = scalar_digits[i][j];
+                let abs_digit = Scalar::from(digit.unsigned_abs() as u64);
+                let digit = if digit < 0 { -abs_digit } else { abs_digit };
+                high[i] = high[i] * sixteen + digit;
+            }
+
+            let expected: EdwardsPoint = high.iter().zip(points.iter()).map(|(h, P)| h * P).sum();
+            assert_eq!(Q, expected);
+        }
+
+        assert_eq!(high, scalars);
+        assert_eq!(Q, Straus::multiscalar_mul(&scalars, &points));
+    }
+}
@@ -1,12 +1,20 @@
 #![allow(non_snake_case)]
 
-use crate::backend::serial::curve_models::ProjectiveNielsPoint;
+use core::cmp::Ordering;
+
+use crate::backend::serial::curve_models::{ProjectiveNielsPoint, ProjectivePoint};
 use crate::edwards::EdwardsPoint;
 use crate::scalar::Scalar;
 use crate::traits::Identity;
-use crate::window::LookupTable;
+use crate::window::{LookupTable, NafLookupTable5};
 
 /// Perform constant-time, variable-base scalar multiplication.
+///
+/// Nothing about the sequence of operations depends on the value of
+/// `scalar`: the main loop always runs for the 63 remaining radix-16
+/// digits, and each digit is looked up with [`LookupTable::select`], which
+/// reads every entry of the table and picks the right one with
+/// conditional moves.
 #[rustfmt::skip] // keep alignment of explanatory comments
 pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
     // Construct a lookup table of [P,2P,3P,4P,5P,6P,7P,8P]
@@ -46,3 +54,38 @@ pub(crate) fn mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
     }
     tmp1.as_extended()
 }
+
+/// Perform variable-time, variable-base scalar multiplication, using a
+/// width-5 NAF of the scalar.
+///
+/// Unlike [`mul`], this skips the doublings for the leading zero digits,
+/// and only adds for the nonzero digits, of which there are about
+/// \\(256/6\\) on average.
+pub(crate) fn vartime_mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
+    let naf = scalar.non_adjacent_form(5);
+
+    // Find the most significant nonzero digit: until then the
+    // accumulator would just be the identity.
+    let top = match naf.iter().rposition(|&digit| digit != 0) {
+        Some(i) => i,
+        None => return EdwardsPoint::identity(),
+    };
+
+    // Odd multiples [P, 3P, 5P, ..., 15P]
+    let table = NafLookupTable5::<ProjectiveNielsPoint>::from(point);
+
+    let mut r = ProjectivePoint::identity();
+    for &digit in naf[..=top].iter().rev() {
+        let mut t = r.double();
+
+        match digit.cmp(&0) {
+            Ordering::Greater => t = &t.as_extended() + &table.select(digit as usize),
+            Ordering::Less => t = &t.as_extended() - &table.select(-digit as usize),
+            Ordering::Equal => {}
+        }
+
+        r = t.as_projective();
+    }
+
+    r.as_extended()
+}
@@ -141,11 +141,11 @@ impl<'a, 'b> Mul<&'b FieldElement51> for &'a FieldElement51 {
         let b4_19 = b[4] * 19;
 
         // Multiply to get 128-bit coefficients of output
-        let     c0: u128 = m(a[0], b[0]) + m(a[4], b1_19) + m(a[3], b2_19) + m(a[2], b3_19) + m(a[1], b4_19);
-        let mut c1: u128 = m(a[1], b[0]) + m(a[0],  b[1]) + m(a[4], b2_19) + m(a[3], b3_19) + m(a[2], b4_19);
-        let mut c2: u128 = m(a[2], b[0]) + m(a[1],  b[1]) + m(a[0],  b[2]) + m(a[4], b3_19) + m(a[3], b4_19);
-        let mut c3: u128 = m(a[3], b[0]) + m(a[2],  b[1]) + m(a[1],  b[2]) + m(a[0],  b[3]) + m(a[4], b4_19);
-        let mut c4: u128 = m(a[4], b[0]) + m(a[3],  b[1]) + m(a[2],  b[2]) + m(a[1],  b[3]) + m(a[0] , b[4]);
+        let c0: u128 = m(a[0], b[0]) + m(a[4], b1_19) + m(a[3], b2_19) + m(a[2], b3_19) + m(a[1], b4_19);
+        let c1: u128 = m(a[1], b[0]) + m(a[0],  b[1]) + m(a[4], b2_19) + m(a[3], b3_19) + m(a[2], b4_19);
+        let c2: u128 = m(a[2], b[0]) + m(a[1],  b[1]) + m(a[0],  b[2]) + m(a[4], b3_19) + m(a[3], b4_19);
+        let c3: u128 = m(a[3], b[0]) + m(a[2],  b[1]) + m(a[1],  b[2]) + m(a[0],  b[3]) + m(a[4], b4_19);
+        let c4: u128 = m(a[4], b[0]) + m(a[3],  b[1]) + m(a[2],  b[2]) + m(a[1],  b[3]) + m(a[0] , b[4]);
 
         // How big are the c[i]? We have
         //
@@ -165,51 +165,12 @@ impl<'a, 'b> Mul<&'b FieldElement51> for &'a FieldElement51 {
         debug_assert!(a[3] < (1 << 54)); debug_assert!(b[3] < (1 << 54));
         debug_assert!(a[4] < (1 << 54)); debug_assert!(b[4] < (1 << 54));
 
-        // Casting to u64 and back tells the compiler that the carry is
-        // bounded by 2^64, so that the addition is a u128 + u64 rather
-        // than u128 + u128.
-
-        const LOW_51_BIT_MASK: u64 = (1u64 << 51) - 1;
-        let mut out = [0u64; 5];
-
-        c1 += ((c0 >> 51) as u64) as u128;
-        out[0] = (c0 as u64) & LOW_51_BIT_MASK;
-
-        c2 += ((c1 >> 51) as u64) as u128;
-        out[1] = (c1 as u64) & LOW_51_BIT_MASK;
-
-        c3 += ((c2 >> 51) as u64) as u128;
-        out[2] = (c2 as u64) & LOW_51_BIT_MASK;
-
-        c4 += ((c3 >> 51) as u64) as u128;
-        out[3] = (c3 as u64) & LOW_51_BIT_MASK;
-
-        let carry: u64 = (c4 >> 51) as u64;
-        out[4] = (c4 as u64) & LOW_51_BIT_MASK;
-
-        // To see that this does not overflow, we need out[0] + carry * 19 < 2^64.
-        //
-        // c4 < a0*b4 + a1*b3 + a2*b2 + a3*b1 + a4*b0 + (carry from c3)
-        //    < 5*(2^(51 + b) * 2^(51 + b)) + (carry from c3)
-        //    < 2^(102 + 2*b + lg(5)) + 2^64.
+        // Moreover c4 < a0*b4 + a1*b3 + a2*b2 + a3*b1 + a4*b0
+        //            < 5*(2^(51 + b) * 2^(51 + b))
+        //            < 2^(102 + 2*b + lg(5)),
         //
-        // When b < 3 we get
-        //
-        // c4 < 2^110.33  so that carry < 2^59.33
-        //
-        // so that
-        //
-        // out[0] + carry * 19 < 2^51 + 19 * 2^59.33 < 2^63.58
-        //
-        // and there is no overflow.
-        out[0] += carry * 19;
-
-        // Now out[1] < 2^51 + 2^(64 -51) = 2^51 + 2^13 < 2^(51 + epsilon).
-        out[1] += out[0] >> 51;
-        out[0] &= LOW_51_BIT_MASK;
-
-        // Now out[i] < 2^(51 + epsilon) for all i.
-        FieldElement51(out)
+        // which is below 2^110.32 when b < 3, as reduce_after_mul requires.
+        FieldElement51::reduce_after_mul([c0, c1, c2, c3, c4])
     }
 }
 
@@ -273,6 +234,11 @@ impl FieldElement51 {
     ]);
 
     /// Invert the sign of this field element
+    ///
+    /// As for subtraction, the input limbs must be below 2^54.  The output
+    /// limbs are below 2^51 + 2^13, the same bound as after a
+    /// multiplication, so the result can be fed into further arithmetic,
+    /// including another negation, without an extra reduction.
     pub fn negate(&mut self) {
         // See commentary in the Sub impl
         let neg = FieldElement51::reduce([
@@ -322,6 +288,62 @@ impl FieldElement51 {
         FieldElement51(limbs)
     }
 
+    /// Given the 128-bit coefficients \\(c\_i\\) of a product, representing
+    /// \\(\sum c\_i 2\^{51 i}\\), carry and reduce to enforce the bound
+    /// 2^(51 + epsilon) on the output limbs.
+    ///
+    /// This is the reduction shared by multiplication and squaring.  It
+    /// requires each \\(c\_i < 2\^{115}\\), so that the carries fit into a
+    /// u64, and \\(c\_4 < 2\^{110.32}\\), so that folding the
+    /// final carry back into the bottom limb does not overflow.
+    #[inline(always)]
+    #[rustfmt::skip] // keep alignment of carry calculations
+    fn reduce_after_mul(c: [u128; 5]) -> FieldElement51 {
+        const LOW_51_BIT_MASK: u64 = (1u64 << 51) - 1;
+        let [c0, mut c1, mut c2, mut c3, mut c4] = c;
+        let mut out = [0u64; 5];
+
+        // Casting to u64 and back tells the compiler that the carry is
+        // bounded by 2^64, so that the addition is a u128 + u64 rather
+        // than u128 + u128.
+        //
+        // The carry (c[i] >> 51) fits into a u64 since c[i] < 2^115, and
+        // adding it to c[i + 1] keeps c[i + 1] < 2^115 + 2^64.
+
+        c1 += ((c0 >> 51) as u64) as u128;
+        out[0] = (c0 as u64) & LOW_51_BIT_MASK;
+
+        c2 += ((c1 >> 51) as u64) as u128;
+        out[1] = (c1 as u64) & LOW_51_BIT_MASK;
+
+        c3 += ((c2 >> 51) as u64) as u128;
+        out[2] = (c2 as u64) & LOW_51_BIT_MASK;
+
+        c4 += ((c3 >> 51) as u64) as u128;
+        out[3] = (c3 as u64) & LOW_51_BIT_MASK;
+
+        let carry: u64 = (c4 >> 51) as u64;
+        out[4] = (c4 as u64) & LOW_51_BIT_MASK;
+
+        // To see that this does not overflow, we need out[0] + carry * 19 < 2^64.
+        //
+        // After adding the carry from c3 we have c4 < 2^110.32 + 2^64
+        // < 2^110.33, so
+        // that carry < 2^59.33 and
+        //
+        // out[0] + carry * 19 < 2^51 + 19 * 2^59.33 < 2^63.58
+        //
+        // and there is no overflow.
+        out[0] += carry * 19;
+
+        // Now out[1] < 2^51 + 2^(64 -51) = 2^51 + 2^13 < 2^(51 + epsilon).
+        out[1] += out[0] >> 51;
+        out[0] &= LOW_51_BIT_MASK;
+
+        // Now out[i] < 2^(51 + epsilon) for all i.
+        FieldElement51(out)
+    }
+
     /// Load a `FieldElement51` from the low 255 bits of a 256-bit
     /// input.
     ///
@@ -334,6 +356,9 @@ impl FieldElement51 {
     /// the canonical encoding, and check that the input was
     /// canonical.
     ///
+    /// For canonical inputs, i.e. those with the high bit cleared and
+    /// encoding a value less than \\(p\\), this is the inverse of
+    /// [`FieldElement51::as_bytes`].
     #[rustfmt::skip] // keep alignment of bit shifts
     pub fn from_bytes(bytes: &[u8; 32]) -> FieldElement51 {
         let load8 = |input: &[u8]| -> u64 {
@@ -364,6 +389,11 @@ impl FieldElement51 {
 
     /// Serialize this `FieldElement51` to a 32-byte array.  The
     /// encoding is canonical.
+    ///
+    /// The output is the little-endian encoding of the representative in
+    /// \\([0, p)\\), so its high bit is always clear, and
+    /// [`FieldElement51::from_bytes`] maps it back to the same field
+    /// element.
     #[rustfmt::skip] // keep alignment of s[*] calculations
     pub fn as_bytes(&self) -> [u8; 32] {
         // Let h = limbs[0] + limbs[1]*2^51 + ... + limbs[4]*2^204.
@@ -485,11 +515,11 @@ impl FieldElement51 {
             // The 128-bit multiplications by 2 turn into 1 slr + 1 slrd each,
             // which doesn't seem any better or worse than doing them as precomputations
             // on the 64-bit inputs.
-            let     c0: u128 = m(a[0],  a[0]) + 2*( m(a[1], a4_19) + m(a[2], a3_19) );
-            let mut c1: u128 = m(a[3], a3_19) + 2*( m(a[0],  a[1]) + m(a[2], a4_19) );
-            let mut c2: u128 = m(a[1],  a[1]) + 2*( m(a[0],  a[2]) + m(a[4], a3_19) );
-            let mut c3: u128 = m(a[4], a4_19) + 2*( m(a[0],  a[3]) + m(a[1],  a[2]) );
-            let mut c4: u128 = m(a[2],  a[2]) + 2*( m(a[0],  a[4]) + m(a[1],  a[3]) );
+            let c0: u128 = m(a[0],  a[0]) + 2*( m(a[1], a4_19) + m(a[2], a3_19) );
+            let c1: u128 = m(a[3], a3_19) + 2*( m(a[0],  a[1]) + m(a[2], a4_19) );
+            let c2: u128 = m(a[1],  a[1]) + 2*( m(a[0],  a[2]) + m(a[4], a3_19) );
+            let c3: u128 = m(a[4], a4_19) + 2*( m(a[0],  a[3]) + m(a[1],  a[2]) );
+            let c4: u128 = m(a[2],  a[2]) + 2*( m(a[0],  a[4]) + m(a[1],  a[3]) );
 
             // Same bound as in multiply:
             //    c[i] < 2^(102 + 2*b) * (1+i + (4-i)*19)
@@ -508,44 +538,8 @@ impl FieldElement51 {
             debug_assert!(a[3] < (1 << 54));
             debug_assert!(a[4] < (1 << 54));
 
-            const LOW_51_BIT_MASK: u64 = (1u64 << 51) - 1;
-
-            // Casting to u64 and back tells the compiler that the carry is bounded by 2^64, so
-            // that the addition is a u128 + u64 rather than u128 + u128.
-            c1 += ((c0 >> 51) as u64) as u128;
-            a[0] = (c0 as u64) & LOW_51_BIT_MASK;
-
-            c2 += ((c1 >> 51) as u64) as u128;
-            a[1] = (c1 as u64) & LOW_51_BIT_MASK;
-
-            c3 += ((c2 >> 51) as u64) as u128;
-            a[2] = (c2 as u64) & LOW_51_BIT_MASK;
-
-            c4 += ((c3 >> 51) as u64) as u128;
-            a[3] = (c3 as u64) & LOW_51_BIT_MASK;
-
-            let carry: u64 = (c4 >> 51) as u64;
-            a[4] = (c4 as u64) & LOW_51_BIT_MASK;
-
-            // To see that this does not overflow, we need a[0] + carry * 19 < 2^64.
-            //
-            // c4 < a2^2 + 2*a0*a4 + 2*a1*a3 + (carry from c3)
-            //    < 2^(102 + 2*b + lg(5)) + 2^64.
-            //
-            // When b < 3 we get
-            //
-            // c4 < 2^110.33  so that carry < 2^59.33
-            //
-            // so that
-            //
-            // a[0] + carry * 19 < 2^51 + 19 * 2^59.33 < 2^63.58
-            //
-            // and there is no overflow.
-            a[0] += carry * 19;
-
-            // Now a[1] < 2^51 + 2^(64 -51) = 2^51 + 2^13 < 2^(51 + epsilon).
-            a[1] += a[0] >> 51;
-            a[0] &= LOW_51_BIT_MASK;
+            // As in multiply, c4 < a2^2 + 2*a0*a4 + 2*a1*a3 < 2^(102 + 2*b + lg(5)).
+            a = FieldElement51::reduce_after_mul([c0, c1, c2, c3, c4]).0;
 
             // Now all a[i] < 2^(51 + epsilon) and a = self^(2^k).
 
@@ -573,3 +567,132 @@ impl FieldElement51 {
         square
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_core::RngCore;
+
+    const LOW_51_BIT_MASK: u64 = (1u64 << 51) - 1;
+
+    /// Check whether the limbs are the unique reduced representative of
+    /// their value, i.e. each limb is below 2^51 and the value is below p.
+    fn is_reduced(limbs: &[u64; 5]) -> bool {
+        let limbs_fit = limbs.iter().all(|&l| l <= LOW_51_BIT_MASK);
+        let at_least_p =
+            limbs[1..].iter().all(|&l| l == LOW_51_BIT_MASK) && limbs[0] >= LOW_51_BIT_MASK - 18;
+        limbs_fit && !at_least_p
+    }
+
+    /// Test that the encoding is injective on reduced field elements, by
+    /// checking that decoding recovers the exact limbs.
+    #[test]
+    fn encoding_is_injective_on_reduced_limbs() {
+        let p_minus_one = [
+            LOW_51_BIT_MASK - 19,
+            LOW_51_BIT_MASK,
+            LOW_51_BIT_MASK,
+            LOW_51_BIT_MASK,
+            LOW_51_BIT_MASK,
+        ];
+        let mut cases = vec![[0u64; 5], [1, 0, 0, 0, 0], p_minus_one];
+        for i in 0..5 {
+            let mut limbs = [0u64; 5];
+            limbs[i] = LOW_51_BIT_MASK;
+            cases.push(limbs);
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let mut limbs = [0u64; 5];
+            for limb in limbs.iter_mut() {
+                *limb = rng.next_u64() & LOW_51_BIT_MASK;
+            }
+            cases.push(limbs);
+        }
+
+        for limbs in cases.into_iter().filter(is_reduced) {
+            let bytes = FieldElement51(limbs).as_bytes();
+            assert_eq!(FieldElement51::from_bytes(&bytes).0, limbs);
+        }
+
+        // The unreduced representative p collides with the reduced zero
+        let mut p = p_minus_one;
+        p[0] += 1;
+        assert!(!is_reduced(&p));
+        assert_eq!(FieldElement51(p).as_bytes(), [0u8; 32]);
+    }
+
+    /// Test the output limb bound of negation, including when negations
+    /// are chained.
+    #[test]
+    fn negate_limb_bound() {
+        const BOUND: u64 = (1 << 51) + (1 << 13);
+
+        let mut rng = rand::thread_rng();
+        let mut cases = vec![[0u64; 5], [(1 << 54) - 1; 5]];
+        for _ in 0..100 {
+            let mut limbs = [0u64; 5];
+            for limb in limbs.iter_mut() {
+                *limb = rng.next_u64() & ((1 << 54) - 1);
+            }
+            cases.push(limbs);
+        }
+
+        for limbs in cases {
+            let x = FieldElement51(limbs);
+            let mut neg_x = -&x;
+            assert!(neg_x.0.iter().all(|&l| l < BOUND));
+            assert_eq!((&x + &neg_x).as_bytes(), [0u8; 32]);
+
+            for _ in 0..10 {
+                neg_x.negate();
+                assert!(neg_x.0.iter().all(|&l| l < BOUND));
+            }
+            assert_eq!(neg_x.as_bytes(), (-&x).as_bytes());
+        }
+    }
+
+    /// Test the carries of the reduction shared by mul and pow2k.
+    #[test]
+    fn reduce_after_mul() {
+        // A carry out of each coefficient moves into the next limb,
+        // and 2^255 = 19 mod p
+        for i in 0..5 {
+            let mut c = [0u128; 5];
+            c[i] = 1 << 51;
+            let mut expected = [0u64; 5];
+            expected[(i + 1) % 5] = if i == 4 { 19 } else { 1 };
+            assert_eq!(FieldElement51::reduce_after_mul(c).0, expected);
+        }
+
+        // The largest coefficients allowed still give limbs below 2^52
+        let c = [
+            (1u128 << 115) - 1,
+            (1u128 << 115) - 1,
+            (1u128 << 115) - 1,
+            (1u128 << 115) - 1,
+            (1u128 << 110) - 1,
+        ];
+        let out = FieldElement51::reduce_after_mul(c);
+        assert!(out.0.iter().all(|&l| l < 1 << 52));
+
+        // The result agrees with the value sum c[i] 2^(51 i) mod p,
+        // computed from the coefficients split into 51-bit pieces
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let mut c = [0u128; 5];
+            for ci in c.iter_mut() {
+                *ci = ((rng.next_u64() as u128) << 46) ^ rng.next_u64() as u128;
+            }
+            let low = c.map(|ci| (ci as u64) & LOW_51_BIT_MASK);
+            let high = c.map(|ci| (ci >> 51) as u64);
+            let high = [high[4] * 19, high[0], high[1], high[2], high[3]];
+            let expected = &FieldElement51::reduce(low) + &FieldElement51::reduce(high);
+            assert_eq!(
+                FieldElement51::reduce_after_mul(c).as_bytes(),
+                expected.as_bytes()
+            );
+        }
+    }
+}
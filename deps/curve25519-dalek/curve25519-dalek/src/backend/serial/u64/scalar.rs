@@ -157,6 +157,10 @@ impl Scalar52 {
     }
 
     /// Compute `a + b` (mod l)
+    ///
+    /// Requires `a, b < l`: then `a + b < 2l < 2^254`, so the sum fits in
+    /// the 260 bits of the limbs without a final carry, and a single
+    /// conditional subtraction of `l` reduces it.
     pub fn add(a: &Scalar52, b: &Scalar52) -> Scalar52 {
         let mut sum = Scalar52::ZERO;
         let mask = (1u64 << 52) - 1;
@@ -167,6 +171,8 @@ impl Scalar52 {
             carry = a[i] + b[i] + (carry >> 52);
             sum[i] = carry & mask;
         }
+        // a + b < 2^254, so nothing was carried out of the top limb
+        debug_assert!(carry >> 52 == 0);
 
         // subtract l if the sum is >= l
         Scalar52::sub(&sum, &constants::L)
@@ -488,6 +494,27 @@ mod test {
         }
     }
 
+    /// Test the largest sum of reduced inputs, (l - 1) + (l - 1) = 2l - 2,
+    /// which the single conditional subtraction reduces to l - 2.
+    #[test]
+    fn add_max() {
+        let mut l_minus_one = constants::L;
+        l_minus_one[0] -= 1;
+        let mut l_minus_two = constants::L;
+        l_minus_two[0] -= 2;
+
+        let res = Scalar52::add(&l_minus_one, &l_minus_one);
+        for i in 0..5 {
+            assert!(res[i] == l_minus_two[i]);
+        }
+
+        // and l - 1 + 1 = l wraps to zero
+        let res = Scalar52::add(&l_minus_one, &Scalar52([1, 0, 0, 0, 0]));
+        for i in 0..5 {
+            assert!(res[i] == 0);
+        }
+    }
+
     #[test]
     fn sub() {
         let res = Scalar52::sub(&A, &B);
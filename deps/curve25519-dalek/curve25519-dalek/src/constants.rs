@@ -65,6 +65,20 @@ pub const RISTRETTO_BASEPOINT_COMPRESSED: CompressedRistretto = CompressedRistre
 /// provides fast scalar multiplication.
 pub const RISTRETTO_BASEPOINT_POINT: RistrettoPoint = RistrettoPoint(ED25519_BASEPOINT_POINT);
 
+/// The domain separation string from which [`NUMS_GENERATOR_H_COMPRESSED`] is derived.
+pub const NUMS_GENERATOR_H_DOMAIN: &[u8] = b"curve25519-dalek NUMS generator H";
+
+/// A second generator \\(H\\) of the prime-order subgroup, in `CompressedEdwardsY` format,
+/// whose discrete log with respect to the basepoint is unknown.
+///
+/// This is `EdwardsPoint::nums_generator::<Sha512>(NUMS_GENERATOR_H_DOMAIN)`, fixed here so
+/// that it can be used without the `digest` feature, and so that protocols relying on it are
+/// not silently affected by changes to the derivation.
+pub const NUMS_GENERATOR_H_COMPRESSED: CompressedEdwardsY = CompressedEdwardsY([
+    0x68, 0x56, 0xdd, 0x61, 0xeb, 0x70, 0x86, 0x58, 0xbc, 0xf3, 0x8c, 0x55, 0x45, 0x3b, 0x34, 0x56,
+    0xbe, 0x73, 0x53, 0x46, 0x46, 0x26, 0xb0, 0xaa, 0x33, 0x91, 0x87, 0xc9, 0x40, 0x17, 0x16, 0xed,
+]);
+
 /// `BASEPOINT_ORDER` is the order of the Ristretto group and of the Ed25519 basepoint, i.e.,
 /// $$
 /// \ell = 2^\{252\} + 27742317777372353535851937790883648493.
@@ -124,6 +138,20 @@ mod test {
         }
     }
 
+    /// Test that the fixed generator H matches its derivation.
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_nums_generator_h() {
+        use crate::edwards::EdwardsPoint;
+
+        let H = EdwardsPoint::nums_generator::<sha2::Sha512>(constants::NUMS_GENERATOR_H_DOMAIN);
+        assert_eq!(H.compress(), constants::NUMS_GENERATOR_H_COMPRESSED);
+
+        assert_eq!(constants::NUMS_GENERATOR_H_COMPRESSED.decompress(), Some(H));
+        assert!(H.is_torsion_free());
+        assert!(!H.is_identity());
+    }
+
     /// Test that SQRT_M1 is the positive square root of -1
     #[test]
     fn test_sqrt_minus_one() {
@@ -133,6 +161,30 @@ mod test {
         assert!(bool::from(!constants::SQRT_M1.is_negative()));
     }
 
+    /// Test that SQRT_M1 matches an independent derivation.  Since
+    /// \\( p \equiv 5 \pmod 8 \\), 2 is not a square, so
+    /// \\( 2\^{(p-1)/4} = 2\^{2\^{253} - 5} \\) is a square root of \\(-1\\).
+    #[test]
+    fn test_sqrt_minus_one_derivation() {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        let two = FieldElement::from_bytes(&bytes);
+        bytes[0] = 32;
+        let two_to_the_5 = FieldElement::from_bytes(&bytes);
+        // pow2k(k) raises to the power 2^k
+        let derived = &two.pow2k(253) * &two_to_the_5.invert();
+
+        let i = constants::SQRT_M1;
+        let minus_i = -&i;
+        assert_eq!(derived.square(), FieldElement::MINUS_ONE);
+        assert!(derived == i || derived == minus_i);
+
+        // -i is the other root, and the negative one
+        assert_ne!(i, minus_i);
+        assert_eq!(minus_i.square(), FieldElement::MINUS_ONE);
+        assert!(bool::from(minus_i.is_negative()));
+    }
+
     #[test]
     fn test_sqrt_constants_sign() {
         let minus_one = FieldElement::MINUS_ONE;
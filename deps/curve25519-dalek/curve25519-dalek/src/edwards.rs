@@ -93,6 +93,9 @@
 // affine and projective cakes and eat both of them too.
 #![allow(non_snake_case)]
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use core::array::TryFromSliceError;
 use core::borrow::Borrow;
 use core::fmt::Debug;
@@ -161,6 +164,9 @@ use crate::traits::{VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
 ///
 /// The first 255 bits of a `CompressedEdwardsY` represent the
 /// \\(y\\)-coordinate.  The high bit of the 32nd byte gives the sign of \\(x\\).
+///
+/// This is a plain `[u8; 32]`, so compression and decompression never
+/// allocate and are available without the `alloc` feature.
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CompressedEdwardsY(pub [u8; 32]);
 
@@ -187,10 +193,46 @@ impl CompressedEdwardsY {
         self.0
     }
 
+    /// Check whether these bytes are the canonical encoding of the
+    /// identity point, i.e. \\(y = 1\\) with the sign bit cleared.
+    ///
+    /// This is a cheap check on the raw bytes which does not require
+    /// decompression.  It is deliberately strict: non-canonical encodings
+    /// which still decompress to the identity, such as \\(y = p + 1\\) or
+    /// \\(y = 1\\) with the sign bit set, are **not** matched.
+    pub fn is_identity_encoding(&self) -> bool {
+        self.ct_eq(&CompressedEdwardsY::identity()).into()
+    }
+
+    /// Check whether this is a canonical encoding, without decompressing.
+    ///
+    /// An encoding is canonical if the \\(y\\)-coordinate is fully
+    /// reduced, i.e. \\(y < p\\), and the sign bit is cleared whenever
+    /// \\(x = 0\\) (which happens exactly when \\(y = \pm 1\\)).  This only
+    /// checks the shape of the encoding: it does not check that \\(y\\) is
+    /// the \\(y\\)-coordinate of a curve point, which is left to
+    /// [`CompressedEdwardsY::decompress`].
+    pub fn is_canonical(&self) -> bool {
+        let mut y_bytes = self.0;
+        let sign_bit = Choice::from(y_bytes[31] >> 7);
+        y_bytes[31] &= 0x7f;
+
+        let y = FieldElement::from_bytes(&y_bytes);
+        let y_is_canonical = y.as_bytes().ct_eq(&y_bytes);
+        // x = 0 exactly when y^2 = 1
+        let x_is_zero = y.square().is_one();
+
+        (y_is_canonical & !(x_is_zero & sign_bit)).into()
+    }
+
     /// Attempt to decompress to an `EdwardsPoint`.
     ///
     /// Returns `None` if the input is not the \\(y\\)-coordinate of a
     /// curve point.
+    ///
+    /// Note that the all-zero encoding is *not* the identity, which has
+    /// \\(y = 1\\).  Since \\(-1\\) is square mod \\(p\\), \\(y = 0\\) gives
+    /// \\(x = \sqrt{-1}\\) and the result is a point of order 4.
     pub fn decompress(&self) -> Option<EdwardsPoint> {
         let (is_valid_y_coord, X, Y, Z) = decompress::step_1(self);
 
@@ -200,6 +242,82 @@ impl CompressedEdwardsY {
             None
         }
     }
+
+    /// Attempt to decompress to an `EdwardsPoint` in the prime-order
+    /// subgroup.
+    ///
+    /// This performs the checks most protocols need on untrusted input.
+    /// Returns `None` if the encoding is not canonical, if the input is
+    /// not the \\(y\\)-coordinate of a curve point, or if the point has a
+    /// torsion component.  In particular all of the low-order points
+    /// other than the identity are rejected; callers which must also
+    /// reject the identity should use
+    /// [`decompress_public_key`](CompressedEdwardsY::decompress_public_key).
+    ///
+    /// This is not constant time, which is fine for public inputs.
+    pub fn decompress_checked(&self) -> Option<EdwardsPoint> {
+        if !self.is_canonical() {
+            return None;
+        }
+        self.decompress().filter(EdwardsPoint::is_torsion_free)
+    }
+
+    /// Attempt to decompress an untrusted public key.
+    ///
+    /// This is [`decompress_checked`](CompressedEdwardsY::decompress_checked)
+    /// followed by rejection of the identity, so `Some(P)` is returned
+    /// exactly when the encoding is canonical and \\(P\\) is a point of
+    /// order \\(\ell\\).
+    ///
+    /// This is not constant time, which is fine for public inputs.
+    pub fn decompress_public_key(&self) -> Option<EdwardsPoint> {
+        self.decompress_checked().filter(|P| !P.is_identity())
+    }
+
+    /// Build a key for caching the product of `scalar` and this point.
+    ///
+    /// The key is the 32 bytes of `scalar` followed by the 32 bytes of
+    /// this encoding.  Since both halves have a fixed length, equal keys
+    /// come from equal byte strings, and therefore from inputs with equal
+    /// products.  The converse does not hold: for example, two encodings
+    /// of the same point give different keys, which only costs a cache
+    /// miss.
+    ///
+    /// The key contains the scalar verbatim, so it must be kept as secret
+    /// as the scalar itself.
+    pub fn mul_cache_key(&self, scalar: &Scalar) -> [u8; 64] {
+        let mut key = [0u8; 64];
+        key[..32].copy_from_slice(scalar.as_bytes());
+        key[32..].copy_from_slice(self.as_bytes());
+        key
+    }
+}
+
+/// The reason [`EdwardsPoint::try_decompress`] rejected its input.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DecodeError {
+    /// The input was not exactly 32 bytes long.
+    WrongLength,
+    /// The input is not a canonical encoding, as defined by
+    /// [`CompressedEdwardsY::is_canonical`].
+    NonCanonical,
+    /// The encoded \\(y\\) is not the \\(y\\)-coordinate of a curve point.
+    NotOnCurve,
+    /// The point is on the curve but not in the prime-order subgroup.
+    /// This covers the low-order points other than the identity, as well
+    /// as any point with a nonzero torsion component.
+    NotTorsionFree,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::WrongLength => write!(f, "encoding is not 32 bytes long"),
+            DecodeError::NonCanonical => write!(f, "encoding is not canonical"),
+            DecodeError::NotOnCurve => write!(f, "encoding is not of a curve point"),
+            DecodeError::NotTorsionFree => write!(f, "point has a torsion component"),
+        }
+    }
 }
 
 mod decompress {
@@ -213,7 +331,7 @@ mod decompress {
         let Z = FieldElement::ONE;
         let YY = Y.square();
         let u = &YY - &Z;                            // u =  y²-1
-        let v = &(&YY * &constants::EDWARDS_D) + &Z; // v = dy²+1
+        let v = &YY.mul_by_d() + &Z;                 // v = dy²+1
         let (is_valid_y_coord, X) = FieldElement::sqrt_ratio_i(&u, &v);
 
         (is_valid_y_coord, X, Y, Z)
@@ -406,6 +524,56 @@ impl CompressedEdwardsY {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl EdwardsPoint {
+    /// Decompress a concatenation of 32-byte `CompressedEdwardsY` encodings.
+    ///
+    /// This is all-or-nothing: either every point decompresses, or no
+    /// points are returned.
+    ///
+    /// # Return
+    ///
+    /// - `Some(points)` if `bytes.len()` is a multiple of 32 and every
+    ///   32-byte chunk decompresses to a point;
+    /// - `None` otherwise.
+    pub fn decompress_batch(bytes: &[u8]) -> Option<Vec<EdwardsPoint>> {
+        if bytes.len() % 32 != 0 {
+            return None;
+        }
+
+        bytes
+            .chunks_exact(32)
+            .map(|chunk| CompressedEdwardsY::from_slice(chunk).ok()?.decompress())
+            .collect()
+    }
+
+    /// Compress a batch of points, sharing a single field inversion
+    /// between them.
+    ///
+    /// The result is the same as calling [`EdwardsPoint::compress`] on
+    /// each point, but the \\(Z\\) coordinates are inverted together with
+    /// one field inversion for the whole batch, rather than one per point.
+    pub fn compress_batch<'a, I>(points: I) -> Vec<CompressedEdwardsY>
+    where
+        I: IntoIterator<Item = &'a EdwardsPoint>,
+    {
+        let points: Vec<&EdwardsPoint> = points.into_iter().collect();
+        let mut recips: Vec<FieldElement> = points.iter().map(|P| P.Z).collect();
+
+        // Every Z is nonzero, so batch_invert replaces each one with
+        // exactly the value 1/Z that `Z.invert()` would give.  The affine
+        // coordinates, and hence their canonical encodings, are therefore
+        // identical to the ones computed one point at a time.
+        FieldElement::batch_invert(&mut recips[..]);
+
+        points
+            .iter()
+            .zip(recips.iter())
+            .map(|(P, recip)| EdwardsPoint::compress_affine(&(&P.X * recip), &(&P.Y * recip)))
+            .collect()
+    }
+}
+
 impl Identity for EdwardsPoint {
     fn identity() -> EdwardsPoint {
         EdwardsPoint {
@@ -475,6 +643,17 @@ impl ConditionallySelectable for EdwardsPoint {
     }
 }
 
+impl EdwardsPoint {
+    /// Select `a` if `bit` is 0 and `b` if `bit` is 1, in constant time.
+    ///
+    /// This is for callers which hold a single bit of a scalar, as in a
+    /// ladder step.  Only the low bit of `bit` is used, so unlike
+    /// converting with `Choice::from` it cannot trip a debug assertion.
+    pub fn select_by_bit(a: &EdwardsPoint, b: &EdwardsPoint, bit: u8) -> EdwardsPoint {
+        EdwardsPoint::conditional_select(a, b, Choice::from(bit & 1))
+    }
+}
+
 // ------------------------------------------------------------------------
 // Equality
 // ------------------------------------------------------------------------
@@ -493,6 +672,13 @@ impl ConstantTimeEq for EdwardsPoint {
 }
 
 impl PartialEq for EdwardsPoint {
+    /// Two points are equal when they have the same affine coordinates,
+    /// regardless of which projective representatives are used.  This is
+    /// computed in constant time with [`ConstantTimeEq::ct_eq`].
+    ///
+    /// Only the comparison is constant time: branching on the returned
+    /// `bool` reveals it, so use `ct_eq` directly unless the outcome is
+    /// public, as with a signature verification result.
     fn eq(&self, other: &EdwardsPoint) -> bool {
         self.ct_eq(other).into()
     }
@@ -500,6 +686,21 @@ impl PartialEq for EdwardsPoint {
 
 impl Eq for EdwardsPoint {}
 
+impl EdwardsPoint {
+    /// Check whether two points are equal, in variable time.
+    ///
+    /// This gives the same result as `==`, by checking that
+    /// \\(XZ' = X'Z\\) and \\(YZ' = Y'Z\\), but returns early when the
+    /// \\(X\\) cross-products differ.
+    ///
+    /// The running time depends on the inputs, so this must only be used
+    /// on public points.
+    pub fn eq_vartime(&self, other: &EdwardsPoint) -> bool {
+        (&self.X * &other.Z).as_bytes() == (&other.X * &self.Z).as_bytes()
+            && (&self.Y * &other.Z).as_bytes() == (&other.Y * &self.Z).as_bytes()
+    }
+}
+
 // ------------------------------------------------------------------------
 // Point conversions
 // ------------------------------------------------------------------------
@@ -541,6 +742,83 @@ impl EdwardsPoint {
         }
     }
 
+    /// Compute the affine coordinates \\((x, y) = (X/Z, Y/Z)\\) of this point.
+    ///
+    /// Since \\(Z \neq 0\\) for every `EdwardsPoint`, this is always
+    /// defined.
+    pub(crate) fn as_affine(&self) -> (FieldElement, FieldElement) {
+        let recip = self.Z.invert();
+        (&self.X * &recip, &self.Y * &recip)
+    }
+
+    /// Encode the affine coordinates \\((x, y)\\) of this point as canonical
+    /// 32-byte little-endian integers.
+    ///
+    /// This is the inverse of [`EdwardsPoint::from_affine_bytes`].
+    pub fn to_affine_bytes(&self) -> ([u8; 32], [u8; 32]) {
+        let (x, y) = self.as_affine();
+        (x.as_bytes(), y.as_bytes())
+    }
+
+    /// Construct a point from its affine coordinates \\((x, y)\\), or
+    /// return `None` if they do not satisfy the curve equation.
+    pub(crate) fn from_affine(x: &FieldElement, y: &FieldElement) -> Option<EdwardsPoint> {
+        let P = EdwardsPoint {
+            X: *x,
+            Y: *y,
+            Z: FieldElement::ONE,
+            T: x * y,
+        };
+        if P.is_valid() {
+            Some(P)
+        } else {
+            None
+        }
+    }
+
+    /// Construct a point from the encodings of its affine coordinates
+    /// \\((x, y)\\), as 32-byte little-endian integers.
+    ///
+    /// Returns `None` if either encoding is not canonical, that is, not
+    /// below \\(p\\) with the high bit clear, or if \\((x, y)\\) is not
+    /// on the curve.
+    pub fn from_affine_bytes(x: &[u8; 32], y: &[u8; 32]) -> Option<EdwardsPoint> {
+        let x_fe = FieldElement::from_bytes(x);
+        let y_fe = FieldElement::from_bytes(y);
+        if x_fe.as_bytes() != *x || y_fe.as_bytes() != *y {
+            return None;
+        }
+        EdwardsPoint::from_affine(&x_fe, &y_fe)
+    }
+
+    /// Decompress an untrusted encoding, reporting why it was rejected.
+    ///
+    /// This accepts exactly the inputs that
+    /// [`CompressedEdwardsY::decompress_checked`] accepts, after checking
+    /// that `bytes` has length 32.  The checks are made in order, and the
+    /// first one to fail determines the error:
+    ///
+    /// 1. [`DecodeError::WrongLength`] if `bytes.len() != 32`;
+    /// 2. [`DecodeError::NonCanonical`] if the encoding is not canonical;
+    /// 3. [`DecodeError::NotOnCurve`] if it does not decompress;
+    /// 4. [`DecodeError::NotTorsionFree`] if the point has a torsion
+    ///    component.
+    ///
+    /// The identity is accepted.  This is not constant time, which is
+    /// fine for public inputs.
+    pub fn try_decompress(bytes: &[u8]) -> Result<EdwardsPoint, DecodeError> {
+        let compressed =
+            CompressedEdwardsY::from_slice(bytes).map_err(|_| DecodeError::WrongLength)?;
+        if !compressed.is_canonical() {
+            return Err(DecodeError::NonCanonical);
+        }
+        let P = compressed.decompress().ok_or(DecodeError::NotOnCurve)?;
+        if !P.is_torsion_free() {
+            return Err(DecodeError::NotTorsionFree);
+        }
+        Ok(P)
+    }
+
     /// Convert this `EdwardsPoint` on the Edwards model to the
     /// corresponding `MontgomeryPoint` on the Montgomery model.
     ///
@@ -563,10 +841,30 @@ impl EdwardsPoint {
     }
 
     /// Compress this point to `CompressedEdwardsY` format.
+    ///
+    /// # Example
+    ///
+    /// Writing the encoding into a caller-provided buffer and reading it
+    /// back needs no allocation:
+    ///
+    /// ```
+    /// use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+    /// use curve25519_dalek::edwards::CompressedEdwardsY;
+    ///
+    /// let mut buf = [0u8; 32];
+    /// buf.copy_from_slice(ED25519_BASEPOINT_POINT.compress().as_bytes());
+    ///
+    /// let point = CompressedEdwardsY(buf).decompress().unwrap();
+    /// assert_eq!(point, ED25519_BASEPOINT_POINT);
+    /// ```
     pub fn compress(&self) -> CompressedEdwardsY {
-        let recip = self.Z.invert();
-        let x = &self.X * &recip;
-        let y = &self.Y * &recip;
+        let (x, y) = self.as_affine();
+        EdwardsPoint::compress_affine(&x, &y)
+    }
+
+    /// Encode the affine coordinates \\((x, y)\\) of a point as a
+    /// `CompressedEdwardsY`.
+    fn compress_affine(x: &FieldElement, y: &FieldElement) -> CompressedEdwardsY {
         let mut s: [u8; 32];
 
         s = y.as_bytes();
@@ -605,6 +903,36 @@ impl EdwardsPoint {
             .expect("Montgomery conversion to Edwards point in Elligator failed")
             .mul_by_cofactor()
     }
+
+    #[cfg(feature = "digest")]
+    /// Derive a "nothing-up-my-sleeve" generator of the prime-order
+    /// subgroup from a domain separation string.
+    ///
+    /// Takes a type parameter `D`, which is any `Digest` producing 64
+    /// bytes of output.
+    ///
+    /// This is intended for protocols which need a second generator
+    /// \\(H\\), for instance for Pedersen commitments, whose discrete log
+    /// with respect to the basepoint must be unknown.  Different domain
+    /// strings give independent generators.  A fixed generator derived
+    /// this way is provided as
+    /// [`NUMS_GENERATOR_H_COMPRESSED`](constants::NUMS_GENERATOR_H_COMPRESSED).
+    ///
+    /// # Implementation
+    ///
+    /// The domain string is hashed to a uniformly distributed point with
+    /// [`RistrettoPoint::hash_from_bytes`], and the cofactor is cleared
+    /// from its Edwards representative, so the result is torsion-free.
+    ///
+    /// [`RistrettoPoint::hash_from_bytes`]: crate::ristretto::RistrettoPoint::hash_from_bytes
+    pub fn nums_generator<D>(domain: &[u8]) -> EdwardsPoint
+    where
+        D: Digest<OutputSize = U64> + Default,
+    {
+        crate::ristretto::RistrettoPoint::hash_from_bytes::<D>(domain)
+            .0
+            .mul_by_cofactor()
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -616,6 +944,58 @@ impl EdwardsPoint {
     pub(crate) fn double(&self) -> EdwardsPoint {
         self.as_projective().double().as_extended()
     }
+
+    /// Compute the successive doublings \\(P, 2P, 4P, \ldots, 2\^{n-1}P\\)
+    /// of this point, where \\(n\\) is `levels`.
+    ///
+    /// The result has `levels` entries, so it is empty when `levels` is
+    /// zero.
+    #[cfg(feature = "alloc")]
+    pub fn doubling_table(&self, levels: usize) -> Vec<EdwardsPoint> {
+        let mut table = Vec::with_capacity(levels);
+        let mut P = *self;
+        for _ in 0..levels {
+            table.push(P);
+            P = P.double();
+        }
+        table
+    }
+
+    /// Double every point of `points` in place.
+    ///
+    /// This is useful when a whole level of a tree of partial sums has
+    /// to be doubled at once.  An empty slice is left unchanged.
+    pub fn double_batch(points: &mut [EdwardsPoint]) {
+        for P in points.iter_mut() {
+            *P = P.double();
+        }
+    }
+
+    /// Compute the odd multiples \\(P, 3P, 5P, \ldots, (2\^{w-1} - 1)P\\)
+    /// of this point, as indexed by the digits of a width-\\(w\\) NAF.
+    ///
+    /// The result has \\(2\^{w-2}\\) entries, with \\((2i + 1)P\\) at index
+    /// \\(i\\), so the multiple for a positive digit \\(d\\) is at index
+    /// \\(d / 2\\).
+    ///
+    /// # Panics
+    ///
+    /// Panics unless \\(2 \leq w \leq 8\\), the widths supported for
+    /// non-adjacent forms of scalars.
+    #[cfg(feature = "alloc")]
+    pub fn odd_multiples_table(&self, w: usize) -> Vec<EdwardsPoint> {
+        assert!((2..=8).contains(&w));
+
+        let size = 1 << (w - 2);
+        let P2 = self.double();
+        let mut table = Vec::with_capacity(size);
+        let mut P = *self;
+        for _ in 0..size {
+            table.push(P);
+            P += P2;
+        }
+        table
+    }
 }
 
 // ------------------------------------------------------------------------
@@ -668,6 +1048,10 @@ impl<T> Sum<T> for EdwardsPoint
 where
     T: Borrow<EdwardsPoint>,
 {
+    /// Add up the points in `iter`, starting from the identity.
+    ///
+    /// This is the exact group sum, torsion components included, and the
+    /// sum of an empty iterator is the identity.
     fn sum<I>(iter: I) -> Self
     where
         I: Iterator<Item = T>,
@@ -683,6 +1067,9 @@ where
 impl<'a> Neg for &'a EdwardsPoint {
     type Output = EdwardsPoint;
 
+    /// Negation sends \\((x, y)\\) to \\((-x, y)\\).  The curve equation
+    /// only involves \\(x\^2\\), so the result is on the curve whenever the
+    /// input is.
     fn neg(self) -> EdwardsPoint {
         EdwardsPoint {
             X: -(&self.X),
@@ -721,6 +1108,9 @@ impl<'a, 'b> Mul<&'b Scalar> for &'a EdwardsPoint {
     type Output = EdwardsPoint;
     /// Scalar multiplication: compute `scalar * self`.
     ///
+    /// This runs in constant time with respect to `scalar`, so it is safe
+    /// to use with secret scalars.
+    ///
     /// For scalar multiplication of a basepoint,
     /// `EdwardsBasepointTable` is approximately 4x faster.
     fn mul(self, scalar: &'b Scalar) -> EdwardsPoint {
@@ -897,7 +1287,80 @@ impl VartimePrecomputedMultiscalarMul for VartimeEdwardsPrecomputation {
     }
 }
 
+/// Streaming computation of \\(a\_1 P\_1 + \cdots + a\_n P\_n\\).
+///
+/// Terms are added one at a time with [`push`](Self::push), and the sum is
+/// obtained with [`finalize`](Self::finalize).  Unlike
+/// [`MultiscalarMul`], the pairs are not buffered: only the running sum
+/// is kept, so memory use is constant in the number of terms.  The price
+/// is that each term costs a full constant-time scalar multiplication,
+/// with none of the sharing done by the batched algorithms.
+///
+/// The result is equal to the multiscalar multiplication of all of the
+/// pairs pushed so far, and to the identity if none were.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MultiscalarAccumulator {
+    sum: EdwardsPoint,
+}
+
+impl MultiscalarAccumulator {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the term \\(aP\\) to the sum.
+    pub fn push(&mut self, a: &Scalar, P: &EdwardsPoint) {
+        self.sum += a * P;
+    }
+
+    /// Return the sum of all of the pushed terms.
+    pub fn finalize(self) -> EdwardsPoint {
+        self.sum
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl EdwardsPoint {
+    /// Compute \\(\pm a\_1 P\_1 \pm \cdots \pm a\_n P\_n\\) in constant time,
+    /// where the \\(i\\)-th term is subtracted when `negate[i]` is `true`.
+    ///
+    /// The terms are negated by negating the points rather than the
+    /// scalars, so the result is the exact signed sum even when the
+    /// points have torsion components, and the signs are applied without
+    /// branching on them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `negate`, `scalars` and `points` have different lengths.
+    pub fn signed_multiscalar_mul(
+        negate: &[bool],
+        scalars: &[Scalar],
+        points: &[EdwardsPoint],
+    ) -> EdwardsPoint {
+        assert_eq!(negate.len(), scalars.len());
+        assert_eq!(negate.len(), points.len());
+
+        let signed_points = negate.iter().zip(points.iter()).map(|(&neg, P)| {
+            let mut P = *P;
+            P.conditional_negate(Choice::from(neg as u8));
+            P
+        });
+        EdwardsPoint::multiscalar_mul(scalars, signed_points)
+    }
+}
+
 impl EdwardsPoint {
+    /// Compute \\(aA\\) in variable time, using the width-5 non-adjacent
+    /// form of \\(a\\).
+    ///
+    /// This computes the same result as `a * A`, and is faster, but it
+    /// leaks information about \\(a\\) through timing, so it must only be
+    /// used with public scalars, such as when verifying signatures.
+    pub fn vartime_mul(&self, a: &Scalar) -> EdwardsPoint {
+        crate::backend::vartime_variable_base_mul(self, a)
+    }
+
     /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
     pub fn vartime_double_scalar_mul_basepoint(
         a: &Scalar,
@@ -908,6 +1371,75 @@ impl EdwardsPoint {
     }
 }
 
+#[cfg(feature = "precomputed-tables")]
+impl EdwardsPoint {
+    /// Compute \\(a\_1 B\_1 + \cdots + a\_n B\_n\\) in constant time, given
+    /// precomputed tables for the fixed points \\(B\_i\\).
+    ///
+    /// This is intended for protocols which repeatedly multiply against
+    /// the same set of generators: the cost of building each
+    /// [`EdwardsBasepointTable`] is paid once, and the doublings of the
+    /// radix-16 method are shared between all of the terms, so the
+    /// result costs \\(64n\\) additions and only four doublings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tables` and `scalars` have different lengths.
+    pub fn multiscalar_mul_precomputed(
+        tables: &[EdwardsBasepointTable],
+        scalars: &[Scalar],
+    ) -> EdwardsPoint {
+        assert_eq!(tables.len(), scalars.len());
+
+        Self::mul_tables(tables.iter().zip(scalars.iter()))
+    }
+
+    /// Compute the Pedersen commitment \\(vG + bH\\) to the value \\(v\\)
+    /// with blinding factor \\(b\\), in constant time, given precomputed
+    /// tables for the generators \\(G\\) and \\(H\\).
+    ///
+    /// For the commitment to be binding, nobody may know the discrete log
+    /// of \\(H\\) with respect to \\(G\\).
+    ///
+    /// Commitments are additively homomorphic: the sum of the commitments
+    /// to \\(v\_1\\) and \\(v\_2\\) with blinding factors \\(b\_1\\) and
+    /// \\(b\_2\\) is the commitment to \\(v\_1 + v\_2\\) with blinding
+    /// factor \\(b\_1 + b\_2\\), where both sums are taken modulo
+    /// \\(\ell\\).
+    pub fn pedersen_commit(
+        value: &Scalar,
+        blinding: &Scalar,
+        G: &EdwardsBasepointTable,
+        H: &EdwardsBasepointTable,
+    ) -> EdwardsPoint {
+        Self::mul_tables([(G, value), (H, blinding)])
+    }
+
+    /// Compute \\(\sum a\_i B\_i\\) for pairs of tables for \\(B\_i\\) and
+    /// scalars \\(a\_i\\), sharing the doublings between the terms.
+    fn mul_tables<'a, I>(terms: I) -> EdwardsPoint
+    where
+        I: IntoIterator<Item = (&'a EdwardsBasepointTable, &'a Scalar)>,
+    {
+        // Split each a_i B_i into its odd and even radix-16 digits, as in
+        // `EdwardsBasepointTable::mul_base`, but accumulate all of the
+        // odd terms before doing the single shared multiplication by 16.
+        let mut odd = EdwardsPoint::identity();
+        let mut even = EdwardsPoint::identity();
+        for (table, scalar) in terms {
+            let a = scalar.as_radix_16();
+            for i in (0..64).filter(|x| x % 2 == 1) {
+                odd = (&odd + &table.0[i / 2].select(a[i])).as_extended();
+            }
+            for i in (0..64).filter(|x| x % 2 == 0) {
+                even = (&even + &table.0[i / 2].select(a[i])).as_extended();
+            }
+        }
+
+        odd.mul_by_pow_2(4) + even
+    }
+}
+
 #[cfg(feature = "precomputed-tables")]
 macro_rules! impl_basepoint_table {
     (Name = $name:ident, LookupTable = $table:ident, Point = $point:ty, Radix = $radix:expr, Additions = $adds:expr) => {
@@ -915,6 +1447,12 @@ macro_rules! impl_basepoint_table {
         /// fixed-base scalar multiplication.  One table, for the Ed25519
         /// basepoint, is provided in the [`constants`] module.
         ///
+        /// Despite the name, a table can be created for any fixed point with
+        /// [`BasepointTable::create`], such as a second generator used for
+        /// Pedersen commitments.  Multiplying the table by a scalar \\(s\\)
+        /// then computes \\(sP\\) in constant time, for the point \\(P\\)
+        /// the table was created from.
+        ///
         /// The basepoint tables are reasonably large, so they should probably be boxed.
         ///
         /// The sizes for the tables and the number of additions required for one scalar
@@ -950,6 +1488,10 @@ macro_rules! impl_basepoint_table {
             type Point = $point;
 
             /// Create a table of precomputed multiples of `basepoint`.
+            ///
+            /// For a radix \\(2\^w\\), entry \\(j - 1\\) of the \\(i\\)-th lookup
+            /// table holds \\([j \cdot 2\^{2wi}] B\\), for \\(1 \leq j \leq 2\^{w-1}\\)
+            /// and \\(0 \leq i < 32\\).
             fn create(basepoint: &$point) -> $name {
                 // XXX use init_with
                 let mut table = $name([$table::default(); 32]);
@@ -1188,9 +1730,15 @@ impl EdwardsPoint {
         self.mul_by_pow_2(3)
     }
 
-    /// Compute \\([2\^k] P \\) by successive doublings. Requires \\( k > 0 \\).
+    /// Compute \\([2\^k] P \\) by successive doublings.
+    ///
+    /// The intermediate results stay in projective coordinates, so the
+    /// extended coordinate \\(T\\) is only computed once, at the end.
+    /// For \\( k = 0 \\) this returns \\(P\\) itself.
     pub(crate) fn mul_by_pow_2(&self, k: u32) -> EdwardsPoint {
-        debug_assert!(k > 0);
+        if k == 0 {
+            return *self;
+        }
         let mut r: CompletedPoint;
         let mut s = self.as_projective();
         for _ in 0..(k - 1) {
@@ -1625,6 +2173,14 @@ mod test {
         0x96, 0x70,
     ]);
 
+    /// The encoding of \\(y = p + 1 = 2\^{255} - 18\\), a non-canonical
+    /// encoding of \\(y = 1\\) which decompresses to the identity.
+    static Y_P_PLUS_ONE: CompressedEdwardsY = CompressedEdwardsY([
+        0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ]);
+
     /// 4493907448824000747700850167940867464579944529806937181821189941592931634714
     pub static A_SCALAR: Scalar = Scalar {
         bytes: [
@@ -1671,6 +2227,80 @@ mod test {
         assert_eq!(bp.compress(), constants::ED25519_BASEPOINT_COMPRESSED);
     }
 
+    /// Test construction of points from affine coordinates.
+    #[test]
+    fn from_affine_bytes() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let base_y = constants::ED25519_BASEPOINT_COMPRESSED.to_bytes();
+        let P = EdwardsPoint::from_affine_bytes(&BASE_X_COORD_BYTES, &base_y).unwrap();
+        assert!(P.is_valid());
+        assert_eq!(P, B);
+
+        // Negating x negates the point
+        let minus_x = (-&FieldElement::from_bytes(&BASE_X_COORD_BYTES)).as_bytes();
+        assert_eq!(
+            EdwardsPoint::from_affine_bytes(&minus_x, &base_y).unwrap(),
+            -B
+        );
+
+        // The identity (0, 1) and the 2-torsion point (0, -1)
+        let zero = FieldElement::ZERO.as_bytes();
+        let one = FieldElement::ONE.as_bytes();
+        let minus_one = FieldElement::MINUS_ONE.as_bytes();
+        assert!(EdwardsPoint::from_affine_bytes(&zero, &one)
+            .unwrap()
+            .is_identity());
+        assert_eq!(
+            EdwardsPoint::from_affine_bytes(&zero, &minus_one).unwrap(),
+            constants::EIGHT_TORSION[4]
+        );
+
+        // Off-curve coordinates are rejected
+        assert!(EdwardsPoint::from_affine_bytes(&one, &one).is_none());
+        assert!(EdwardsPoint::from_affine_bytes(&BASE_X_COORD_BYTES, &one).is_none());
+
+        // Non-canonical encodings are rejected, even of on-curve points
+        let mut p_plus_zero = [0xffu8; 32];
+        p_plus_zero[0] = 0xed;
+        p_plus_zero[31] = 0x7f;
+        assert_eq!(FieldElement::from_bytes(&p_plus_zero), FieldElement::ZERO);
+        assert!(EdwardsPoint::from_affine_bytes(&p_plus_zero, &one).is_none());
+        let mut base_y_high_bit = base_y;
+        base_y_high_bit[31] |= 0x80;
+        assert!(EdwardsPoint::from_affine_bytes(&BASE_X_COORD_BYTES, &base_y_high_bit).is_none());
+    }
+
+    /// Test extraction of affine coordinates.
+    #[test]
+    fn to_affine_bytes() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let (x, y) = B.to_affine_bytes();
+        assert_eq!(x, BASE_X_COORD_BYTES);
+        assert_eq!(y, constants::ED25519_BASEPOINT_COMPRESSED.to_bytes());
+
+        let (x, y) = EdwardsPoint::identity().to_affine_bytes();
+        assert_eq!(x, FieldElement::ZERO.as_bytes());
+        assert_eq!(y, FieldElement::ONE.as_bytes());
+
+        // The coordinates don't depend on the projective representative,
+        // and round trip through from_affine_bytes
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let P = EdwardsPoint::mul_base(&Scalar::random(&mut rng)) + constants::EIGHT_TORSION[1];
+            assert_ne!(P.Z, FieldElement::ONE);
+            let (x, y) = P.to_affine_bytes();
+            let Q = EdwardsPoint::from_affine_bytes(&x, &y).unwrap();
+            assert_eq!(Q.Z, FieldElement::ONE);
+            assert_eq!(Q, P);
+            assert_eq!(Q.to_affine_bytes(), (x, y));
+
+            // The compressed encoding is y with the sign of x
+            let mut s = y;
+            s[31] ^= (x[0] & 1) << 7;
+            assert_eq!(CompressedEdwardsY(s), P.compress());
+        }
+    }
+
     /// Test sign handling in decompression
     #[test]
     fn decompression_sign_handling() {
@@ -1749,6 +2379,64 @@ mod test {
         assert!(bool::from(id1.ct_eq(&id2)));
     }
 
+    /// Check that `==` agrees with comparing the (affine) compressed
+    /// encodings, across different representatives and torsion components.
+    #[test]
+    fn equality_matches_compressed_equality() {
+        let P = EdwardsPoint::mul_base(&A_SCALAR);
+        let mut points = vec![EdwardsPoint::identity(), P, -P, P.double()];
+        for T in constants::EIGHT_TORSION.iter() {
+            points.push(T + P);
+            points.push(*T);
+        }
+
+        for A in &points {
+            // Rescaling the coordinates doesn't change the point
+            let two = &FieldElement::ONE + &FieldElement::ONE;
+            let A_scaled = EdwardsPoint {
+                X: &two * &A.X,
+                Y: &two * &A.Y,
+                Z: &two * &A.Z,
+                T: &two * &A.T,
+            };
+            assert_eq!(*A, A_scaled);
+
+            for B in &points {
+                assert_eq!(A == B, A.compress() == B.compress());
+                assert_eq!(A == B, bool::from(A.ct_eq(B)));
+            }
+        }
+    }
+
+    /// Test that the identity is a unit for addition on either side, for
+    /// each of the addition formulas.
+    #[test]
+    fn identity_is_additive_unit() {
+        let id = EdwardsPoint::identity();
+        let P = EdwardsPoint::mul_base(&A_SCALAR);
+        let mut points = vec![id, P, -P];
+        for T in constants::EIGHT_TORSION.iter() {
+            points.push(T + P);
+            points.push(*T);
+        }
+
+        for P in &points {
+            for sum in [
+                id + P,
+                P + id,
+                (&id + &P.as_projective_niels()).as_extended(),
+                (&id + &P.as_affine_niels()).as_extended(),
+                (P + &id.as_projective_niels()).as_extended(),
+                (P + &id.as_affine_niels()).as_extended(),
+            ] {
+                assert!(sum.is_valid());
+                assert_eq!(sum, *P);
+            }
+            assert!((P - P).is_identity());
+            assert!((-P + P).is_identity());
+        }
+    }
+
     /// Sanity check for conversion to precomputed points
     #[cfg(feature = "precomputed-tables")]
     #[test]
@@ -1783,6 +2471,32 @@ mod test {
         assert_eq!(aB_1.compress(), aB_2.compress());
     }
 
+    /// Test that mul_base, the precomputed basepoint table and
+    /// variable-base multiplication all agree, so callers may freely
+    /// choose the table-accelerated path.
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn mul_base_vs_table_vs_variable_base() {
+        let mut rng = rand::thread_rng();
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            A_SCALAR,
+            crate::scalar::test::LARGEST_UNREDUCED_SCALAR,
+            Scalar::random(&mut rng),
+            Scalar::random(&mut rng),
+        ];
+
+        for s in &scalars {
+            let sB_1 = EdwardsPoint::mul_base(s);
+            let sB_2 = ED25519_BASEPOINT_TABLE * s;
+            let sB_3 = constants::ED25519_BASEPOINT_POINT * s;
+            assert_eq!(sB_1.compress(), sB_2.compress());
+            assert_eq!(sB_2.compress(), sB_3.compress());
+        }
+    }
+
     /// Test scalar_mul versus a known scalar multiple from ed25519.py
     #[test]
     fn scalar_mul_vs_ed25519py() {
@@ -1799,6 +2513,68 @@ mod test {
         );
     }
 
+    /// Test that doubling agrees with adding a point to itself, and keeps
+    /// points on the curve.
+    #[test]
+    fn double_matches_add_self() {
+        let mut rng = rand::thread_rng();
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        let I = EdwardsPoint::identity();
+        assert!(I.double().is_identity());
+        assert!(I.double().is_valid());
+
+        let mut points = [B; 12];
+        points[1] = B * Scalar::random(&mut rng);
+        points[2] = B * Scalar::random(&mut rng) + constants::EIGHT_TORSION[5];
+        points[3] = B.double().double() - B;
+        points[4..].copy_from_slice(&constants::EIGHT_TORSION);
+
+        for P in &points {
+            let P2 = P.double();
+            assert!(P2.is_valid());
+            assert_eq!(P2, P + P);
+            assert_eq!(P2.compress(), (P + P).compress());
+        }
+    }
+
+    /// Test the relations a ladder step relies on between a sum and a
+    /// double: \\( (P + Q) + (P - Q) = 2P \\) and \\( (P + Q) - Q = P \\),
+    /// including when \\( Q \\) is \\( \pm P \\) or has a torsion component.
+    #[test]
+    fn add_and_double_consistency() {
+        let mut rng = rand::thread_rng();
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        for T in &constants::EIGHT_TORSION {
+            let P = B * Scalar::random(&mut rng);
+            let R = B * Scalar::random(&mut rng) + T;
+            for Q in [R, P, -P, EdwardsPoint::identity(), *T] {
+                let sum = P + Q;
+                assert_eq!(sum + (P - Q), P.double());
+                assert_eq!(sum - Q, P);
+            }
+        }
+    }
+
+    #[test]
+    fn double_batch() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let mut points = [
+            B,
+            EdwardsPoint::identity(),
+            constants::EIGHT_TORSION[1],
+            B * A_SCALAR + constants::EIGHT_TORSION[2],
+        ];
+        let expected = points.map(|P| P + P);
+
+        EdwardsPoint::double_batch(&mut points);
+        assert_eq!(points, expected);
+        assert_eq!(points[0].compress(), BASE2_CMPRSSD);
+
+        EdwardsPoint::double_batch(&mut []);
+    }
+
     /// Test that computing 2*basepoint is the same as basepoint.double()
     #[test]
     fn basepoint_mult_two_vs_basepoint2() {
@@ -1834,6 +2610,159 @@ mod test {
         assert_eq!(aP128, aP256);
     }
 
+    /// Test that a table for a point other than the basepoint multiplies
+    /// that point.
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn basepoint_table_for_other_point() {
+        let mut rng = rand::thread_rng();
+        let H = EdwardsPoint::mul_base(&Scalar::random(&mut rng));
+        let table = EdwardsBasepointTable::create(&H);
+        assert_eq!(table.basepoint(), H);
+
+        for _ in 0..10 {
+            let s = Scalar::random(&mut rng);
+            assert_eq!(&table * &s, s * H);
+        }
+    }
+
+    /// Test the Pedersen commitment equation.
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn pedersen_commit() {
+        let mut rng = rand::thread_rng();
+        let G = constants::ED25519_BASEPOINT_POINT;
+        let H = EdwardsPoint::mul_base(&Scalar::random(&mut rng));
+        let G_table = EdwardsBasepointTable::create(&G);
+        let H_table = EdwardsBasepointTable::create(&H);
+
+        let v = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        let C = EdwardsPoint::pedersen_commit(&v, &b, &G_table, &H_table);
+        assert_eq!(C, v * G + b * H);
+
+        assert!(
+            EdwardsPoint::pedersen_commit(&Scalar::ZERO, &Scalar::ZERO, &G_table, &H_table)
+                .is_identity()
+        );
+    }
+
+    /// Test that derived generators are torsion-free, non-trivial and
+    /// domain separated.
+    #[cfg(feature = "digest")]
+    #[test]
+    fn nums_generator() {
+        let H1 = EdwardsPoint::nums_generator::<sha2::Sha512>(b"test generator 1");
+        let H2 = EdwardsPoint::nums_generator::<sha2::Sha512>(b"test generator 2");
+
+        for H in [H1, H2] {
+            assert!(H.is_valid());
+            assert!(H.is_torsion_free());
+            assert!(!H.is_identity());
+            assert_ne!(H, constants::ED25519_BASEPOINT_POINT);
+        }
+        assert_ne!(H1, H2);
+        assert_eq!(
+            H1,
+            EdwardsPoint::nums_generator::<sha2::Sha512>(b"test generator 1")
+        );
+    }
+
+    /// Test that adding commitments adds the committed values and blinding
+    /// factors modulo l.
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn pedersen_commit_is_homomorphic() {
+        let mut rng = rand::thread_rng();
+        let G_table = EdwardsBasepointTable::create(&constants::ED25519_BASEPOINT_POINT);
+        let H_table =
+            EdwardsBasepointTable::create(&EdwardsPoint::mul_base(&Scalar::random(&mut rng)));
+        let commit =
+            |v: &Scalar, b: &Scalar| EdwardsPoint::pedersen_commit(v, b, &G_table, &H_table);
+
+        let (v1, b1) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+        let (v2, b2) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+        assert_eq!(
+            commit(&v1, &b1) + commit(&v2, &b2),
+            commit(&(v1 + v2), &(b1 + b2))
+        );
+
+        // The sums wrap around modulo l: -1 + 1 = 0
+        let minus_one = -Scalar::ONE;
+        assert_eq!(
+            commit(&minus_one, &minus_one) + commit(&Scalar::ONE, &Scalar::ONE),
+            EdwardsPoint::identity()
+        );
+    }
+
+    /// Test that multiscalar multiplication with precomputed tables agrees
+    /// with the sum of the individual products.
+    #[cfg(all(feature = "precomputed-tables", feature = "alloc"))]
+    #[test]
+    fn multiscalar_mul_precomputed_vs_individual() {
+        let mut rng = rand::thread_rng();
+
+        let points: Vec<EdwardsPoint> = (0..5)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+            .collect();
+        let mut scalars: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        scalars[3] = Scalar::ZERO;
+        scalars[4] = crate::scalar::test::LARGEST_UNREDUCED_SCALAR;
+
+        let tables: Vec<EdwardsBasepointTable> =
+            points.iter().map(EdwardsBasepointTable::create).collect();
+
+        let expected: EdwardsPoint = points.iter().zip(scalars.iter()).map(|(P, s)| P * s).sum();
+        let result = EdwardsPoint::multiscalar_mul_precomputed(&tables, &scalars);
+        assert_eq!(result.compress(), expected.compress());
+
+        // The empty sum is the identity.
+        assert!(EdwardsPoint::multiscalar_mul_precomputed(&[], &[]).is_identity());
+    }
+
+    /// Check that every entry of each basepoint table holds the expected
+    /// multiple of the basepoint.
+    #[cfg(feature = "precomputed-tables")]
+    #[test]
+    fn basepoint_table_entries() {
+        fn check_entries<'a, I>(tables: I, w: u32, P: &EdwardsPoint)
+        where
+            I: ExactSizeIterator<Item = &'a [AffineNielsPoint]>,
+        {
+            assert_eq!(tables.len(), 32);
+            // base = 2^(2wi) * P
+            let mut base = *P;
+            for table in tables {
+                assert_eq!(table.len(), 1 << (w - 1));
+                // multiple = j * base
+                let mut multiple = base;
+                for entry in table.iter() {
+                    let entry = (&EdwardsPoint::identity() + entry).as_extended();
+                    assert_eq!(entry, multiple);
+                    multiple += base;
+                }
+                base = base.mul_by_pow_2(2 * w);
+            }
+        }
+
+        let P = &constants::ED25519_BASEPOINT_POINT;
+
+        let table = EdwardsBasepointTableRadix16::create(P);
+        check_entries(table.0.iter().map(|t| &t.0[..]), 4, P);
+
+        let table = EdwardsBasepointTableRadix32::create(P);
+        check_entries(table.0.iter().map(|t| &t.0[..]), 5, P);
+
+        let table = EdwardsBasepointTableRadix64::create(P);
+        check_entries(table.0.iter().map(|t| &t.0[..]), 6, P);
+
+        let table = EdwardsBasepointTableRadix128::create(P);
+        check_entries(table.0.iter().map(|t| &t.0[..]), 7, P);
+
+        let table = EdwardsBasepointTableRadix256::create(P);
+        check_entries(table.0.iter().map(|t| &t.0[..]), 8, P);
+    }
+
     /// Check unreduced scalar multiplication by the basepoint tables is the same no matter what
     /// radix the table is.
     #[cfg(feature = "precomputed-tables")]
@@ -1874,6 +2803,30 @@ mod test {
         );
     }
 
+    /// Check that converting an arbitrarily scaled projective point to
+    /// extended coordinates gives a valid point with the same affine value.
+    #[test]
+    fn projective_to_extended_is_valid() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let P = EdwardsPoint::mul_base(&Scalar::random(&mut rng));
+
+            let mut lambda_bytes = [0u8; 32];
+            rng.fill_bytes(&mut lambda_bytes);
+            let lambda = FieldElement::from_bytes(&lambda_bytes);
+            let P_proj = P.as_projective();
+            let scaled = ProjectivePoint {
+                X: &lambda * &P_proj.X,
+                Y: &lambda * &P_proj.Y,
+                Z: &lambda * &P_proj.Z,
+            };
+
+            let P_ext = scaled.as_extended();
+            assert!(P_ext.is_valid());
+            assert_eq!(P_ext, P);
+        }
+    }
+
     /// Test computing 16*basepoint vs mul_by_pow_2(4)
     #[test]
     fn basepoint16_vs_mul_by_pow_2_4() {
@@ -1881,6 +2834,179 @@ mod test {
         assert_eq!(bp16.compress(), BASE16_CMPRSSD);
     }
 
+    /// Test mul_by_pow_2 against repeated doubling, including k = 0
+    #[test]
+    fn mul_by_pow_2_vs_double() {
+        let P = constants::ED25519_BASEPOINT_POINT * A_SCALAR + constants::EIGHT_TORSION[1];
+        assert_eq!(P.mul_by_pow_2(0).compress(), P.compress());
+
+        let mut doubled = P;
+        for k in 1..20 {
+            doubled = doubled.double();
+            let Q = P.mul_by_pow_2(k);
+            assert!(Q.is_valid());
+            assert_eq!(Q, doubled);
+        }
+        assert_eq!(P.mul_by_pow_2(8), P * Scalar::from(256u64));
+    }
+
+    /// Test that the doubling table holds the powers-of-two multiples
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn doubling_table() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        assert!(B.doubling_table(0).is_empty());
+
+        let table = B.doubling_table(5);
+        assert_eq!(table.len(), 5);
+        assert_eq!(table[0], B);
+        assert_eq!(table[1].compress(), BASE2_CMPRSSD);
+        assert_eq!(table[4].compress(), BASE16_CMPRSSD);
+        for (i, P) in table.iter().enumerate() {
+            assert_eq!(*P, B.mul_by_pow_2(i as u32));
+        }
+    }
+
+    /// Test that multiplying by zero gives the identity and multiplying by
+    /// one gives the point back, on every scalar multiplication path.
+    #[test]
+    fn mul_by_zero_and_one() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let zero = Scalar::ZERO;
+        let one = Scalar::ONE;
+
+        let mut points = vec![EdwardsPoint::identity(), B, B * A_SCALAR];
+        for T in constants::EIGHT_TORSION.iter() {
+            points.push(*T);
+            points.push(B + T);
+        }
+
+        for P in &points {
+            assert!((P * zero).is_identity());
+            assert!((zero * P).is_identity());
+            assert!(P.vartime_mul(&zero).is_identity());
+            assert_eq!(P * one, *P);
+            assert_eq!(one * P, *P);
+            assert_eq!(P.vartime_mul(&one), *P);
+
+            assert!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(&zero, P, &zero).is_identity()
+            );
+            assert_eq!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(&one, P, &zero),
+                *P
+            );
+            assert_eq!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(&zero, P, &one),
+                B
+            );
+
+            #[cfg(feature = "alloc")]
+            {
+                assert!(EdwardsPoint::multiscalar_mul([zero], [P]).is_identity());
+                assert!(EdwardsPoint::vartime_multiscalar_mul([zero], [P]).is_identity());
+                assert_eq!(EdwardsPoint::multiscalar_mul([one], [P]), *P);
+                assert_eq!(EdwardsPoint::vartime_multiscalar_mul([one], [P]), *P);
+            }
+        }
+
+        assert!(EdwardsPoint::mul_base(&zero).is_identity());
+        assert_eq!(EdwardsPoint::mul_base(&one), B);
+        #[cfg(feature = "precomputed-tables")]
+        {
+            assert!((ED25519_BASEPOINT_TABLE * &zero).is_identity());
+            assert_eq!(ED25519_BASEPOINT_TABLE * &one, B);
+        }
+    }
+
+    /// Test that scalar multiplication is linear in the scalar:
+    /// \\( (a + b)P = aP + bP \\).
+    ///
+    /// Since \\( a + b \\) is reduced mod \\( \ell \\), this only holds for
+    /// points in the prime-order subgroup; for a point with a torsion
+    /// component the two sides differ by \\( \ell \\) times that component.
+    #[test]
+    fn scalar_mul_distributes_over_scalar_addition() {
+        let mut rng = rand::thread_rng();
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        for _ in 0..8 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let P = B * Scalar::random(&mut rng);
+
+            assert_eq!(P * (a + b), P * a + P * b);
+            assert_eq!(
+                P.vartime_mul(&(a + b)),
+                P.vartime_mul(&a) + P.vartime_mul(&b)
+            );
+            assert_eq!(
+                EdwardsPoint::mul_base(&(a + b)),
+                EdwardsPoint::mul_base(&a) + EdwardsPoint::mul_base(&b)
+            );
+            assert_eq!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(&a, &P, &b),
+                P * a + B * b
+            );
+            // a + (l - a) wraps around to zero
+            assert!((P * (a + -a)).is_identity());
+            assert_eq!(P * a + P * -a, EdwardsPoint::identity());
+        }
+
+        // The prime-order restriction is necessary
+        let a = -Scalar::ONE;
+        let b = Scalar::ONE;
+        let T = constants::EIGHT_TORSION[1];
+        assert!((T * (a + b)).is_identity());
+        assert_ne!(T * a + T * b, EdwardsPoint::identity());
+    }
+
+    /// Test that variable-time multiplication agrees with the constant-time one
+    #[test]
+    fn vartime_mul_vs_consttime_mul() {
+        let mut rng = rand::thread_rng();
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let P = B * A_SCALAR + constants::EIGHT_TORSION[3];
+
+        assert!(P.vartime_mul(&Scalar::ZERO).is_identity());
+        assert_eq!(P.vartime_mul(&Scalar::ONE), P);
+        assert_eq!(B.vartime_mul(&A_SCALAR), B * A_SCALAR);
+
+        for _ in 0..20 {
+            let a = Scalar::random(&mut rng);
+            assert_eq!(P.vartime_mul(&a), P * a);
+        }
+    }
+
+    /// Test the odd multiples tables, against the fixed NAF lookup table
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn odd_multiples_table() {
+        let P = constants::ED25519_BASEPOINT_POINT * A_SCALAR;
+        assert_eq!(P.odd_multiples_table(2), [P]);
+
+        for w in 2..=8 {
+            let table = P.odd_multiples_table(w);
+            assert_eq!(table.len(), 1 << (w - 2));
+            for (i, multiple) in table.iter().enumerate() {
+                assert_eq!(*multiple, P * Scalar::from(2 * i as u64 + 1));
+            }
+        }
+
+        let naf_table = crate::window::NafLookupTable5::<ProjectiveNielsPoint>::from(&P);
+        for (i, multiple) in P.odd_multiples_table(5).iter().enumerate() {
+            let selected = (&EdwardsPoint::identity() + &naf_table.select(2 * i + 1)).as_extended();
+            assert_eq!(*multiple, selected);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn odd_multiples_table_rejects_width_one() {
+        constants::ED25519_BASEPOINT_POINT.odd_multiples_table(1);
+    }
+
     /// Check that mul_base_clamped and mul_clamped agree
     #[test]
     fn mul_base_clamped() {
@@ -1959,6 +3085,15 @@ mod test {
         let sum: EdwardsPoint = mapped.sum();
 
         assert_eq!(sum, P1 * s + P2 * s);
+
+        // Test that torsion components are summed too
+        // (EIGHT_TORSION[i] = [i]T, and 0 + 1 + ... + 7 = 28 = 4 mod 8.)
+        let sum: EdwardsPoint = constants::EIGHT_TORSION.iter().sum();
+        assert_eq!(sum, constants::EIGHT_TORSION[4]);
+        let sum: EdwardsPoint = constants::EIGHT_TORSION[..4].iter().sum();
+        assert_eq!(sum, constants::EIGHT_TORSION[6]);
+        let sum: EdwardsPoint = constants::EIGHT_TORSION[..3].iter().sum();
+        assert_eq!(sum, constants::EIGHT_TORSION[3]);
     }
 
     /// Test that the conditional assignment trait works for AffineNielsPoints.
@@ -1974,6 +3109,117 @@ mod test {
         assert_eq!(p1, bp);
     }
 
+    #[test]
+    fn select_by_bit() {
+        let A = constants::ED25519_BASEPOINT_POINT;
+        let B = A * A_SCALAR;
+
+        assert_eq!(
+            EdwardsPoint::select_by_bit(&A, &B, 0).compress(),
+            A.compress()
+        );
+        assert_eq!(
+            EdwardsPoint::select_by_bit(&A, &B, 1).compress(),
+            B.compress()
+        );
+
+        // Only the low bit is used
+        assert_eq!(
+            EdwardsPoint::select_by_bit(&A, &B, 2).compress(),
+            A.compress()
+        );
+        assert_eq!(
+            EdwardsPoint::select_by_bit(&A, &B, 0xff).compress(),
+            B.compress()
+        );
+
+        // Selecting bit by bit recovers a scalar multiple
+        let mut acc = EdwardsPoint::identity();
+        for bit in A_SCALAR.bits_le().rev() {
+            acc = acc.double();
+            acc = EdwardsPoint::select_by_bit(&acc, &(acc + A), bit as u8);
+        }
+        assert_eq!(acc, B);
+    }
+
+    /// Test conditional negation of EdwardsPoints and of the Niels forms
+    /// selected from lookup tables.
+    #[test]
+    fn conditional_negate() {
+        let id = EdwardsPoint::identity();
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let points = [
+            id,
+            B,
+            B * A_SCALAR,
+            constants::EIGHT_TORSION[1],
+            B + constants::EIGHT_TORSION[2],
+        ];
+
+        for P in &points {
+            let mut Q = *P;
+            Q.conditional_negate(Choice::from(0));
+            assert_eq!(Q.compress(), P.compress());
+            Q.conditional_negate(Choice::from(1));
+            assert!(Q.is_valid());
+            assert_eq!(Q, -P);
+            assert!((Q + P).is_identity());
+
+            let mut P_pn = P.as_projective_niels();
+            P_pn.conditional_negate(Choice::from(1));
+            assert_eq!((&id + &P_pn).as_extended(), -P);
+            let mut P_an = P.as_affine_niels();
+            P_an.conditional_negate(Choice::from(1));
+            assert_eq!((&id + &P_an).as_extended(), -P);
+        }
+
+        // The identity is its own negation
+        let mut Q = id;
+        Q.conditional_negate(Choice::from(1));
+        assert!(Q.is_identity());
+    }
+
+    #[test]
+    fn neg_properties() {
+        let mut rng = rand::thread_rng();
+        let id = EdwardsPoint::identity();
+        let B = constants::ED25519_BASEPOINT_POINT;
+
+        assert!((-id).is_identity());
+        // The 2-torsion point (0, -1) is also its own negation
+        assert_eq!(-constants::EIGHT_TORSION[4], constants::EIGHT_TORSION[4]);
+
+        let mut points = [id; 12];
+        points[1] = B;
+        points[2] = B * Scalar::random(&mut rng);
+        points[3] = B * Scalar::random(&mut rng) + constants::EIGHT_TORSION[3];
+        points[4..].copy_from_slice(&constants::EIGHT_TORSION);
+
+        for P in &points {
+            let neg_P = -P;
+            assert!(neg_P.is_valid());
+            assert!((P + neg_P).is_identity());
+            assert_eq!(-neg_P, *P);
+            // The scalar -1 is the integer l - 1, so this needs P to be
+            // in the prime-order subgroup
+            if P.is_torsion_free() {
+                assert_eq!(neg_P, P * -Scalar::ONE);
+            }
+
+            // Only the sign bit of the encoding changes, unless x = 0
+            let mut bytes = P.compress().to_bytes();
+            if !bool::from(P.X.is_zero()) {
+                bytes[31] ^= 0x80;
+            }
+            assert_eq!(neg_P.compress().to_bytes(), bytes);
+
+            for Q in &points {
+                assert_eq!(P - Q, P + (-Q));
+                assert_eq!(P - Q, -(Q - P));
+            }
+        }
+    }
+
     #[test]
     fn is_small_order() {
         // The basepoint has large prime order
@@ -1992,6 +3238,328 @@ mod test {
         );
     }
 
+    #[test]
+    fn is_identity_encoding() {
+        assert!(CompressedEdwardsY::identity().is_identity_encoding());
+        assert!(EdwardsPoint::identity().compress().is_identity_encoding());
+        assert!(!constants::ED25519_BASEPOINT_COMPRESSED.is_identity_encoding());
+
+        // y = 1 with the sign bit set still decompresses to the identity,
+        // but is not the canonical encoding.
+        let mut signed_identity = [0u8; 32];
+        signed_identity[0] = 1;
+        signed_identity[31] = 0x80;
+        let signed_identity = CompressedEdwardsY(signed_identity);
+        assert!(signed_identity.decompress().unwrap().is_identity());
+        assert!(!signed_identity.is_identity_encoding());
+
+        // y = p + 1 = 2^255 - 18 is a non-canonical encoding of y = 1.
+        assert!(Y_P_PLUS_ONE.decompress().unwrap().is_identity());
+        assert!(!Y_P_PLUS_ONE.is_identity_encoding());
+    }
+
+    /// Test that decompressing then compressing arbitrary bytes is the
+    /// identity whenever the encoding is canonical and decompresses.
+    #[test]
+    fn decompress_compress_round_trip() {
+        let mut rng = rand::thread_rng();
+        let mut decompressed = 0;
+
+        for _ in 0..1000 {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let repr = CompressedEdwardsY(bytes);
+
+            if let Some(P) = repr.decompress() {
+                assert!(P.is_valid());
+                if repr.is_canonical() {
+                    assert_eq!(P.compress(), repr);
+                }
+                decompressed += 1;
+            }
+        }
+        assert!(decompressed > 0);
+
+        // Non-canonical encodings decompress to a point whose encoding differs
+        let mut bytes = CompressedEdwardsY::identity().to_bytes();
+        bytes[31] |= 0x80;
+        let repr = CompressedEdwardsY(bytes);
+        assert!(!repr.is_canonical());
+        assert_ne!(repr.decompress().unwrap().compress(), repr);
+    }
+
+    #[test]
+    fn compressed_is_canonical() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let P = EdwardsPoint::mul_base(&Scalar::random(&mut rng));
+            assert!(P.compress().is_canonical());
+        }
+        assert!(CompressedEdwardsY::identity().is_canonical());
+        for torsion_point in &constants::EIGHT_TORSION {
+            assert!(torsion_point.compress().is_canonical());
+        }
+
+        // y = 1 and y = -1 with the sign bit set have x = 0, and are rejected.
+        let mut bytes = CompressedEdwardsY::identity().to_bytes();
+        bytes[31] |= 0x80;
+        assert!(!CompressedEdwardsY(bytes).is_canonical());
+        let mut bytes = FieldElement::MINUS_ONE.as_bytes();
+        assert!(CompressedEdwardsY(bytes).is_canonical());
+        bytes[31] |= 0x80;
+        assert!(!CompressedEdwardsY(bytes).is_canonical());
+
+        // y = p + 1 is not reduced.
+        assert!(!Y_P_PLUS_ONE.is_canonical());
+
+        // Flipping the sign bit of a point with x != 0 keeps it canonical.
+        let mut bytes = BASE2_CMPRSSD.to_bytes();
+        bytes[31] ^= 0x80;
+        assert!(CompressedEdwardsY(bytes).is_canonical());
+    }
+
+    #[test]
+    fn decompress_checked() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        assert_eq!(BASE2_CMPRSSD.decompress_checked().unwrap(), B.double());
+        assert!(CompressedEdwardsY::identity()
+            .decompress_checked()
+            .unwrap()
+            .is_identity());
+
+        // Low-order and mixed-order points are rejected
+        for torsion_point in &constants::EIGHT_TORSION[1..] {
+            assert!(torsion_point.compress().decompress_checked().is_none());
+            assert!((B + torsion_point)
+                .compress()
+                .decompress_checked()
+                .is_none());
+        }
+
+        // y = p + 1 decompresses to the identity, but is not canonical
+        assert!(Y_P_PLUS_ONE.decompress().is_some());
+        assert!(Y_P_PLUS_ONE.decompress_checked().is_none());
+
+        // y = 2 is not the y-coordinate of a curve point
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(CompressedEdwardsY(bytes).decompress_checked().is_none());
+    }
+
+    /// Test that the all-zero encoding decompresses to a point of order 4.
+    #[test]
+    fn decompress_zero_bytes() {
+        let zero = CompressedEdwardsY([0u8; 32]);
+        assert_ne!(zero, CompressedEdwardsY::default());
+
+        let P = zero.decompress().unwrap();
+        assert!(P.is_valid());
+        assert_eq!(P.compress(), zero);
+        assert_eq!(P.X.square(), -&FieldElement::ONE);
+        assert!(!P.double().is_identity());
+        assert!(P.double().double().is_identity());
+        assert!(constants::EIGHT_TORSION.contains(&P));
+
+        // It is small order, so the checked variant rejects it
+        assert!(zero.decompress_checked().is_none());
+    }
+
+    #[test]
+    fn decompress_public_key() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        assert_eq!(BASE2_CMPRSSD.decompress_public_key().unwrap(), B.double());
+
+        // The identity is rejected
+        assert!(CompressedEdwardsY::identity()
+            .decompress_public_key()
+            .is_none());
+
+        // Low-order and mixed-order points are rejected
+        for torsion_point in &constants::EIGHT_TORSION[1..] {
+            assert!(torsion_point.compress().decompress_public_key().is_none());
+            assert!((B + torsion_point)
+                .compress()
+                .decompress_public_key()
+                .is_none());
+        }
+
+        // Non-canonical encodings are rejected: y = p + 1, and y = 1 with
+        // the sign bit set, both of which decompress to the identity
+        assert!(Y_P_PLUS_ONE.decompress_public_key().is_none());
+        let mut bytes = CompressedEdwardsY::identity().to_bytes();
+        bytes[31] |= 0x80;
+        assert!(CompressedEdwardsY(bytes).decompress().is_some());
+        assert!(CompressedEdwardsY(bytes).decompress_public_key().is_none());
+
+        // y = 2 is not the y-coordinate of a curve point
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        assert!(CompressedEdwardsY(bytes).decompress_public_key().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decompress_batch() {
+        let points = [
+            constants::ED25519_BASEPOINT_POINT,
+            EdwardsPoint::identity(),
+            constants::EIGHT_TORSION[1],
+        ];
+        let mut bytes = Vec::new();
+        for P in &points {
+            bytes.extend_from_slice(P.compress().as_bytes());
+        }
+
+        assert_eq!(EdwardsPoint::decompress_batch(&bytes).unwrap(), points);
+        assert_eq!(EdwardsPoint::decompress_batch(&[]).unwrap(), []);
+
+        // Truncated input
+        assert!(EdwardsPoint::decompress_batch(&bytes[..bytes.len() - 1]).is_none());
+
+        // One invalid point fails the whole batch. y = 2 is not the
+        // y-coordinate of a curve point.
+        let mut invalid = [0u8; 32];
+        invalid[0] = 2;
+        assert!(CompressedEdwardsY(invalid).decompress().is_none());
+        bytes[32..64].copy_from_slice(&invalid);
+        assert!(EdwardsPoint::decompress_batch(&bytes).is_none());
+    }
+
+    #[test]
+    fn mul_cache_key() {
+        let B = constants::ED25519_BASEPOINT_COMPRESSED;
+        let I = CompressedEdwardsY::identity();
+        let scalars = [Scalar::ZERO, Scalar::ONE, A_SCALAR, B_SCALAR];
+
+        let key = B.mul_cache_key(&A_SCALAR);
+        assert_eq!(key[..32], A_SCALAR.to_bytes());
+        assert_eq!(key[32..], B.to_bytes());
+
+        // Distinct inputs give distinct keys
+        for (i, s) in scalars.iter().enumerate() {
+            for (j, t) in scalars.iter().enumerate() {
+                for (P, Q) in [(B, B), (B, I), (I, B), (I, I)] {
+                    let same = i == j && P == Q;
+                    assert_eq!(P.mul_cache_key(s) == Q.mul_cache_key(t), same);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn eq_vartime_matches_eq() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let points = [
+            B,
+            EdwardsPoint::identity(),
+            constants::EIGHT_TORSION[2],
+            CompressedEdwardsY(B.compress().to_bytes())
+                .decompress()
+                .unwrap(),
+        ];
+        // The same points, in different projective representations
+        let other_representations = [
+            B.double() - B,
+            B.double() - B.double(),
+            constants::EIGHT_TORSION[1].double(),
+            (B + B) - B,
+        ];
+
+        for P in points.iter().chain(other_representations.iter()) {
+            for Q in points.iter().chain(other_representations.iter()) {
+                assert_eq!(P.eq_vartime(Q), P == Q);
+            }
+        }
+        for (P, Q) in points.iter().zip(other_representations.iter()) {
+            assert!(P.eq_vartime(Q));
+            assert!(Q.eq_vartime(P));
+        }
+
+        // Unequal points in different representations, including ones that
+        // share an X or Y coordinate
+        let B_other = other_representations[0];
+        for Q in [-B, constants::EIGHT_TORSION[2], constants::EIGHT_TORSION[4]] {
+            assert!(!Q.eq_vartime(&B_other));
+            assert!(!B_other.eq_vartime(&Q));
+        }
+        let minus_B_other = -B_other;
+        assert!(!B.eq_vartime(&minus_B_other));
+        assert!(!minus_B_other.eq_vartime(&B));
+    }
+
+    #[test]
+    fn try_decompress() {
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let B_bytes = B.compress().to_bytes();
+        assert_eq!(EdwardsPoint::try_decompress(&B_bytes), Ok(B));
+        assert_eq!(
+            EdwardsPoint::try_decompress(CompressedEdwardsY::identity().as_bytes()),
+            Ok(EdwardsPoint::identity())
+        );
+
+        assert_eq!(
+            EdwardsPoint::try_decompress(&B_bytes[..31]),
+            Err(DecodeError::WrongLength)
+        );
+        assert_eq!(
+            EdwardsPoint::try_decompress(&[0u8; 33]),
+            Err(DecodeError::WrongLength)
+        );
+        assert_eq!(
+            EdwardsPoint::try_decompress(&[]),
+            Err(DecodeError::WrongLength)
+        );
+
+        // y = p + 1 decompresses to the identity but is not canonical
+        assert!(Y_P_PLUS_ONE.decompress().is_some());
+        assert_eq!(
+            EdwardsPoint::try_decompress(Y_P_PLUS_ONE.as_bytes()),
+            Err(DecodeError::NonCanonical)
+        );
+
+        // y = 2 is not the y-coordinate of a curve point
+        let mut y_two = [0u8; 32];
+        y_two[0] = 2;
+        assert_eq!(
+            EdwardsPoint::try_decompress(&y_two),
+            Err(DecodeError::NotOnCurve)
+        );
+
+        // Low-order points, and points with a torsion component
+        for T in &constants::EIGHT_TORSION[1..] {
+            assert_eq!(
+                EdwardsPoint::try_decompress(T.compress().as_bytes()),
+                Err(DecodeError::NotTorsionFree)
+            );
+            assert_eq!(
+                EdwardsPoint::try_decompress((B + T).compress().as_bytes()),
+                Err(DecodeError::NotTorsionFree)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn compress_batch_matches_compress() {
+        let mut rng = rand::thread_rng();
+        let mut points: Vec<EdwardsPoint> = (0..16)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+            .collect();
+        points.push(EdwardsPoint::identity());
+        points.extend_from_slice(&constants::EIGHT_TORSION);
+        // Points with Z != 1
+        points.push(constants::ED25519_BASEPOINT_POINT.mul_by_pow_2(3));
+        points.push(points[0] + constants::EIGHT_TORSION[3]);
+
+        let compressed = EdwardsPoint::compress_batch(&points);
+        assert_eq!(compressed.len(), points.len());
+        for (P, P_compressed) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*P_compressed, P.compress());
+        }
+
+        assert!(EdwardsPoint::compress_batch(&[]).is_empty());
+    }
+
     #[test]
     fn is_identity() {
         assert!(EdwardsPoint::identity().is_identity());
@@ -2092,6 +3660,88 @@ mod test {
         }
     }
 
+    /// Test that the streaming accumulator agrees with multiscalar_mul
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiscalar_accumulator_vs_multiscalar_mul() {
+        let mut rng = rand::thread_rng();
+
+        assert!(MultiscalarAccumulator::new().finalize().is_identity());
+
+        let xs = (0..16)
+            .map(|_| Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        let Gs = (0..16)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut rng)))
+            .collect::<Vec<_>>();
+
+        let mut acc = MultiscalarAccumulator::new();
+        for (x, G) in xs.iter().zip(Gs.iter()) {
+            acc.push(x, G);
+        }
+
+        assert_eq!(acc.finalize(), EdwardsPoint::multiscalar_mul(&xs, &Gs));
+    }
+
+    /// Test that signed_multiscalar_mul agrees with adding and subtracting
+    /// the terms one at a time
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn signed_multiscalar_mul() {
+        let mut rng = rand::thread_rng();
+
+        let n = 16;
+        let negate = (0..n).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let xs = (0..n).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let Gs = (0..n)
+            .map(|i| {
+                EdwardsPoint::mul_base(&Scalar::random(&mut rng)) + constants::EIGHT_TORSION[i % 8]
+            })
+            .collect::<Vec<_>>();
+
+        let mut expected = EdwardsPoint::identity();
+        for ((&neg, x), G) in negate.iter().zip(xs.iter()).zip(Gs.iter()) {
+            if neg {
+                expected -= x * G;
+            } else {
+                expected += x * G;
+            }
+        }
+        assert_eq!(
+            EdwardsPoint::signed_multiscalar_mul(&negate, &xs, &Gs),
+            expected
+        );
+
+        // With no negations this is the ordinary multiscalar_mul
+        assert_eq!(
+            EdwardsPoint::signed_multiscalar_mul(&[false; 16], &xs, &Gs),
+            EdwardsPoint::multiscalar_mul(&xs, &Gs)
+        );
+        assert!(EdwardsPoint::signed_multiscalar_mul(&[], &[], &[]).is_identity());
+    }
+
+    /// Test that summing points agrees with multiscalar_mul with all-one
+    /// scalars
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn sum_vs_multiscalar_mul_with_ones() {
+        let mut rng = rand::thread_rng();
+
+        for n in [0, 1, 2, 16, 200] {
+            let Gs = (0..n)
+                .map(|i| {
+                    EdwardsPoint::mul_base(&Scalar::random(&mut rng))
+                        + constants::EIGHT_TORSION[i % 8]
+                })
+                .collect::<Vec<_>>();
+            let ones = vec![Scalar::ONE; n];
+
+            let sum: EdwardsPoint = Gs.iter().sum();
+            assert_eq!(sum, EdwardsPoint::multiscalar_mul(&ones, &Gs));
+            assert_eq!(sum, EdwardsPoint::vartime_multiscalar_mul(&ones, &Gs));
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn vartime_precomputed_vs_nonprecomputed_multiscalar() {
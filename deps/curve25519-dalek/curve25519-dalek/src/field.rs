@@ -31,6 +31,7 @@ use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
 use crate::backend;
 use crate::constants;
@@ -115,6 +116,48 @@ impl FieldElement {
         bytes.ct_eq(&zero)
     }
 
+    /// Determine if this `FieldElement` is one.
+    ///
+    /// Like `is_zero`, this compares the canonical encoding, so any
+    /// representation of one mod p is detected.
+    ///
+    /// # Return
+    ///
+    /// If one, return `Choice(1)`.  Otherwise, return `Choice(0)`.
+    pub(crate) fn is_one(&self) -> Choice {
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        let bytes = self.as_bytes();
+
+        bytes.ct_eq(&one)
+    }
+
+    /// Determine if this `FieldElement` is equal to the small constant `v`.
+    ///
+    /// The comparison is made on the canonical encoding, so any
+    /// representation of `v` mod p compares equal.
+    #[cfg(test)]
+    pub(crate) fn equals_u64(&self, v: u64) -> bool {
+        let mut v_bytes = [0u8; 32];
+        v_bytes[..8].copy_from_slice(&v.to_le_bytes());
+
+        self.as_bytes().ct_eq(&v_bytes).into()
+    }
+
+    /// Compute `2*self`.
+    ///
+    /// Like addition, this does not reduce the result, so each limb of
+    /// the output is at most twice the corresponding limb of the input.
+    pub(crate) fn double(&self) -> FieldElement {
+        self + self
+    }
+
+    /// Compute `d*self`, where \\(d = -121665/121666\\) is the Edwards
+    /// curve constant.
+    pub(crate) fn mul_by_d(&self) -> FieldElement {
+        self * &constants::EDWARDS_D
+    }
+
     /// Compute (self^(2^250-1), self^11), used as a helper function
     /// within invert() and pow22523().
     #[rustfmt::skip] // keep alignment of explanatory comments
@@ -214,6 +257,16 @@ impl FieldElement {
         t21
     }
 
+    /// Compute the inverse of this field element, if it exists.
+    ///
+    /// # Return
+    ///
+    /// - `Some(1/self)` if `self` is nonzero;
+    /// - `None` if `self` is zero.
+    pub(crate) fn invert_ct(&self) -> CtOption<FieldElement> {
+        CtOption::new(self.invert(), !self.is_zero())
+    }
+
     /// Raise this field element to the power (p-5)/8 = 2^252 -3.
     #[rustfmt::skip] // keep alignment of explanatory comments
     #[allow(clippy::let_and_return)]
@@ -240,6 +293,13 @@ impl FieldElement {
     /// - `(Choice(0), zero)        ` if `v` is zero and `u` is nonzero;
     /// - `(Choice(0), +sqrt(i*u/v))` if `u/v` is nonsquare (so `i*u/v` is square).
     ///
+    /// Equivalently, writing `(was_square, r)` for the result:
+    ///
+    /// - `r` is always nonnegative;
+    /// - if `was_square` is set, then `v * r^2 == u`;
+    /// - if `was_square` is unset and `v` is nonzero, then `v * r^2 == i*u`;
+    /// - if `v` is zero, then `r` is zero, and `was_square` is set iff `u` is zero.
+    ///
     pub(crate) fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (Choice, FieldElement) {
         // Using the same trick as in ed25519 decoding, we merge the
         // inversion, the square root, and the square test as follows.
@@ -263,6 +323,10 @@ impl FieldElement {
         // If vr^2 =  u, then sqrt(u/v) = r.
         // If vr^2 = -u, then sqrt(u/v) = r*sqrt(-1).
         //
+        // Otherwise vr^2 = ±iu and u/v is nonsquare.  Since p = 5 mod 8,
+        // i is also nonsquare, so i*u/v is square, and the same argument
+        // gives sqrt(i*u/v) = r or r*sqrt(-1).
+        //
         // If v is zero, r is also zero.
 
         let v3 = &v.square() * v;
@@ -308,6 +372,7 @@ impl FieldElement {
 #[cfg(test)]
 mod test {
     use crate::field::*;
+    use rand_core::RngCore;
 
     /// Random element a of GF(2^255-19), from Sage
     /// a = 1070314506888354081329385823235218444233221\
@@ -339,6 +404,13 @@ mod test {
         0x21, 0x55,
     ];
 
+    /// Encoding of 1 as 1 + (2^255 - 19) = 2^255 - 18, which is not reduced
+    static ONE_ENCODED_WRONGLY_BYTES: [u8; 32] = [
+        0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ];
+
     #[test]
     fn a_mul_a_vs_a_squared_constant() {
         let a = FieldElement::from_bytes(&A_BYTES);
@@ -369,6 +441,16 @@ mod test {
         assert_eq!(FieldElement::ONE, &a * &should_be_inverse);
     }
 
+    #[test]
+    fn invert_ct_zero_and_nonzero() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let ainv = FieldElement::from_bytes(&AINV_BYTES);
+        assert_eq!(a.invert_ct().unwrap(), ainv);
+        assert_eq!(FieldElement::ONE.invert_ct().unwrap(), FieldElement::ONE);
+        assert!(bool::from(FieldElement::ZERO.invert_ct().is_none()));
+        assert!(bool::from((&a - &a).invert_ct().is_none()));
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn batch_invert_a_matches_nonbatched() {
@@ -425,6 +507,121 @@ mod test {
         assert!(bool::from(!sqrt.is_negative()));
     }
 
+    /// Test that \\(1, -1, i, -i\\) are exactly the fourth roots of unity,
+    /// which is what lets `sqrt_ratio_i` classify \\(vr\^2 / u\\) by
+    /// comparing against \\(u\\), \\(-u\\) and \\(-iu\\) only.
+    #[test]
+    fn fourth_roots_of_unity() {
+        let one = FieldElement::ONE;
+        let i = constants::SQRT_M1;
+        let roots = [one, -&one, i, -&i];
+
+        // Return the index in `roots` of x, or None if x^4 != 1
+        let classify = |x: &FieldElement| -> Option<usize> {
+            let index = roots.iter().position(|root| root == x);
+            assert_eq!(index.is_some(), x.square().square() == one);
+            index
+        };
+
+        for (k, root) in roots.iter().enumerate() {
+            assert_eq!(classify(root), Some(k));
+        }
+        assert_eq!(i.square(), -&one);
+
+        // x^((p-1)/4) = x * (x^((p-5)/8))^2 is always a fourth root of
+        // unity for x != 0, and is one of 1, -1 exactly when x is square.
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let mut x_bytes = [0u8; 32];
+            rng.fill_bytes(&mut x_bytes);
+            let x = FieldElement::from_bytes(&x_bytes);
+            if bool::from(x.is_zero()) {
+                continue;
+            }
+            assert_eq!(classify(&x), None);
+
+            let chi = &x * &x.pow_p58().square();
+            let k = classify(&chi).unwrap();
+            let (is_square, _) = FieldElement::sqrt_ratio_i(&x, &one);
+            assert_eq!(bool::from(is_square), k < 2);
+
+            let chi_of_square = &x.square() * &x.square().pow_p58().square();
+            assert!(classify(&chi_of_square).unwrap() < 2);
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_nonsquare_branch() {
+        let mut rng = rand::thread_rng();
+        let one = FieldElement::ONE;
+        let i = constants::SQRT_M1;
+        let two = &one + &one; // 2 is nonsquare mod p.
+
+        // i is nonsquare too
+        let (was_square, _) = FieldElement::sqrt_ratio_i(&i, &one);
+        assert!(!bool::from(was_square));
+
+        for _ in 0..64 {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let w = FieldElement::from_bytes(&bytes);
+            rng.fill_bytes(&mut bytes);
+            let v = FieldElement::from_bytes(&bytes);
+            if bool::from(w.is_zero() | v.is_zero()) {
+                continue;
+            }
+
+            for nonsquare in [two, i] {
+                // u/v = nonsquare * w^2 is nonsquare
+                let u = &(&nonsquare * &w.square()) * &v;
+                let (was_square, r) = FieldElement::sqrt_ratio_i(&u, &v);
+                assert!(!bool::from(was_square));
+                assert!(!bool::from(r.is_negative()));
+                assert_eq!(&v * &r.square(), &i * &u);
+
+                // and i*u/v is square, with the same root
+                let (was_square, s) = FieldElement::sqrt_ratio_i(&(&i * &u), &v);
+                assert!(bool::from(was_square));
+                assert_eq!(s, r);
+            }
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_contract_random() {
+        let mut rng = rand::thread_rng();
+        let i = constants::SQRT_M1;
+        let zero = FieldElement::ZERO;
+
+        for _ in 0..100 {
+            let mut u_bytes = [0u8; 32];
+            let mut v_bytes = [0u8; 32];
+            rng.fill_bytes(&mut u_bytes);
+            rng.fill_bytes(&mut v_bytes);
+            let u = FieldElement::from_bytes(&u_bytes);
+            let v = FieldElement::from_bytes(&v_bytes);
+
+            let (was_square, r) = FieldElement::sqrt_ratio_i(&u, &v);
+            assert!(bool::from(!r.is_negative()));
+            let check = &v * &r.square();
+            if bool::from(was_square) {
+                assert_eq!(check, u);
+            } else {
+                assert_eq!(check, &i * &u);
+            }
+
+            // Squares are always recognised as such.
+            let (was_square, r) = FieldElement::sqrt_ratio_i(&(&u.square() * &v), &v);
+            assert!(bool::from(was_square));
+            assert_eq!(r.square(), u.square());
+
+            // A zero denominator only succeeds for a zero numerator.
+            let (was_square, r) = FieldElement::sqrt_ratio_i(&u, &zero);
+            assert_eq!(bool::from(was_square), bool::from(u.is_zero()));
+            assert_eq!(r, zero);
+        }
+    }
+
     #[test]
     fn a_p58_vs_ap58_constant() {
         let a = FieldElement::from_bytes(&A_BYTES);
@@ -447,6 +644,54 @@ mod test {
         251, 97, 127, 70, 210, 58, 23, 166, 87, 240, 169, 184, 178,
     ];
 
+    #[test]
+    fn double_vs_add_and_mul() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let one = FieldElement::ONE;
+        let two = &one + &one;
+        assert_eq!(a.double(), &a + &a);
+        assert_eq!(a.double(), &a * &two);
+        assert_eq!(FieldElement::ZERO.double(), FieldElement::ZERO);
+        assert_eq!(FieldElement::MINUS_ONE.double(), -&two);
+
+        let asq = FieldElement::from_bytes(&ASQ_BYTES);
+        assert_eq!(a.square2(), asq.double());
+    }
+
+    #[test]
+    fn mul_by_d() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        assert_eq!(a.mul_by_d(), &a * &constants::EDWARDS_D);
+        assert_eq!(FieldElement::ONE.mul_by_d(), constants::EDWARDS_D);
+        assert_eq!(FieldElement::ZERO.mul_by_d(), FieldElement::ZERO);
+
+        // d = -121665/121666, so 121666*d = -121665
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&121666u64.to_le_bytes());
+        let denominator = FieldElement::from_bytes(&bytes);
+        bytes[..8].copy_from_slice(&121665u64.to_le_bytes());
+        let numerator = FieldElement::from_bytes(&bytes);
+        assert_eq!(denominator.mul_by_d(), -&numerator);
+    }
+
+    #[test]
+    fn equals_u64_small_constants() {
+        let one = FieldElement::ONE;
+        let two = &one + &one;
+        assert!(FieldElement::ZERO.equals_u64(0));
+        assert!(one.equals_u64(1));
+        assert!(two.equals_u64(2));
+        assert!(!two.equals_u64(1));
+        assert!((&FieldElement::MINUS_ONE + &one).equals_u64(0));
+
+        let max = FieldElement::from_bytes(&[
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]);
+        assert!(max.equals_u64(u64::MAX));
+        assert!(!max.equals_u64(u64::MAX - 1));
+    }
+
     #[test]
     fn from_bytes_highbit_is_ignored() {
         let mut cleared_bytes = B_BYTES;
@@ -471,14 +716,8 @@ mod test {
 
     #[test]
     fn encoding_is_canonical() {
-        // Encode 1 wrongly as 1 + (2^255 - 19) = 2^255 - 18
-        let one_encoded_wrongly_bytes: [u8; 32] = [
-            0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
-            0xff, 0xff, 0xff, 0x7f,
-        ];
-        // Decode to a field element
-        let one = FieldElement::from_bytes(&one_encoded_wrongly_bytes);
+        // Decode 1, encoded wrongly, to a field element
+        let one = FieldElement::from_bytes(&ONE_ENCODED_WRONGLY_BYTES);
         // .. then check that the encoding is correct
         let one_bytes = one.as_bytes();
         assert_eq!(one_bytes[0], 1);
@@ -487,6 +726,50 @@ mod test {
         }
     }
 
+    #[test]
+    fn bytes_round_trip() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            // Encoding then decoding is the identity on field elements
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let x = FieldElement::from_bytes(&bytes);
+            assert_eq!(FieldElement::from_bytes(&x.as_bytes()), x);
+
+            // Decoding then encoding is the identity on canonical bytes
+            let canonical = x.as_bytes();
+            assert_eq!(canonical[31] >> 7, 0);
+            assert_eq!(FieldElement::from_bytes(&canonical).as_bytes(), canonical);
+        }
+
+        // The largest canonical encoding, p - 1
+        let minus_one = FieldElement::MINUS_ONE.as_bytes();
+        assert_eq!(minus_one[0], 0xec);
+        assert!(minus_one[1..31].iter().all(|&b| b == 0xff));
+        assert_eq!(minus_one[31], 0x7f);
+        assert_eq!(
+            FieldElement::from_bytes(&minus_one),
+            FieldElement::MINUS_ONE
+        );
+    }
+
+    #[test]
+    fn is_one() {
+        assert!(bool::from(FieldElement::ONE.is_one()));
+        assert!(!bool::from(FieldElement::ZERO.is_one()));
+        assert!(!bool::from(FieldElement::MINUS_ONE.is_one()));
+        assert!(!bool::from(FieldElement::ONE.double().is_one()));
+
+        // p + 1 is an unreduced representation of one
+        let one = FieldElement::from_bytes(&ONE_ENCODED_WRONGLY_BYTES);
+        assert!(bool::from(one.is_one()));
+
+        let a = FieldElement::from_bytes(&A_BYTES);
+        assert!(bool::from((&a * &a.invert()).is_one()));
+        assert!(bool::from(FieldElement::MINUS_ONE.square().is_one()));
+        assert!(!bool::from(a.is_one()));
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn batch_invert_empty() {
@@ -5,7 +5,6 @@
 use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
-use subtle::ConstantTimeEq;
 
 use super::lizard_constants;
 use crate::constants;
@@ -33,7 +32,7 @@ impl JacobiPoint {
         // Special case: s = 0.  If s is zero, either t = 1 or t = -1.
         // If t=1, then sqrt(i*d) is the preimage.  Otherwise it's 0.
         let s_is_zero = self.S.is_zero();
-        let t_equals_one = self.T.ct_eq(&FieldElement::ONE);
+        let t_equals_one = self.T.is_one();
         out.conditional_assign(&lizard_constants::SQRT_ID, t_equals_one);
         let mut ret = s_is_zero;
         let mut done = s_is_zero;
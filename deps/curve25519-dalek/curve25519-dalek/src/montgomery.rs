@@ -70,6 +70,14 @@ use zeroize::Zeroize;
 
 /// Holds the \\(u\\)-coordinate of a point on the Montgomery form of
 /// Curve25519 or its twist.
+///
+/// The bytes are the little-endian encoding of \\(u\\) from RFC 7748.
+/// Unlike an Edwards \\(y\\)-coordinate there is no sign bit: as the RFC
+/// requires, the high bit is ignored and the remaining 255 bits are read
+/// modulo \\(p\\) whenever the point is used, so encodings which differ only
+/// in the high bit, or by a multiple of \\(p\\), are equal.  The bytes
+/// themselves are stored and returned unchanged; use [`mask_u_coordinate`]
+/// to clear the high bit.
 #[derive(Copy, Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MontgomeryPoint(pub [u8; 32]);
@@ -230,14 +238,12 @@ impl MontgomeryPoint {
         // on the twist, not the curve, so we can reject it early.
 
         let u = FieldElement::from_bytes(&self.0);
-
-        if u == FieldElement::MINUS_ONE {
-            return None;
-        }
-
         let one = FieldElement::ONE;
 
-        let y = &(&u - &one) * &(&u + &one).invert();
+        // u + 1 has no inverse exactly when u = -1.
+        let u_plus_one_inv: FieldElement = Option::from((&u + &one).invert_ct())?;
+
+        let y = &(&u - &one) * &u_plus_one_inv;
 
         let mut y_bytes = y.as_bytes();
         y_bytes[31] ^= sign << 7;
@@ -246,6 +252,19 @@ impl MontgomeryPoint {
     }
 }
 
+/// _Masks_ the given little-endian encoding of a Montgomery \\(u\\)-coordinate, clearing the
+/// most-significant bit and leaving the other 255 bits unchanged.
+///
+/// RFC 7748 requires implementations of X25519 to ignore the high bit of a received
+/// \\(u\\)-coordinate.  The arithmetic in this module already does so, so masking never changes
+/// which point a `MontgomeryPoint` represents; it is useful when the bytes themselves are compared,
+/// stored or forwarded and should not carry the extra bit.
+#[must_use]
+pub const fn mask_u_coordinate(mut bytes: [u8; 32]) -> [u8; 32] {
+    bytes[31] &= 0b0111_1111;
+    bytes
+}
+
 /// Perform the Elligator2 mapping to a Montgomery point.
 ///
 /// See <https://tools.ietf.org/html/draft-irtf-cfrg-hash-to-curve-10#section-6.7.1>
@@ -514,6 +533,52 @@ mod test {
         assert_eq!(u18, u18_unred);
     }
 
+    /// Check that the high bit of the encoding is ignored, but kept.
+    #[test]
+    fn high_bit_ignored() {
+        let mut csprng = rand_core::OsRng;
+        let s = Scalar::random(&mut csprng);
+        let P = constants::X25519_BASEPOINT * s;
+
+        // Outputs are canonical, so the high bit is clear
+        assert_eq!(P.to_bytes()[31] & 0x80, 0);
+        assert_eq!(MontgomeryPoint(P.to_bytes()).to_bytes(), P.to_bytes());
+
+        let mut high_bytes = P.to_bytes();
+        high_bytes[31] |= 0x80;
+        let P_high = MontgomeryPoint(high_bytes);
+
+        // The bytes round-trip unchanged, high bit included
+        assert_eq!(P_high.to_bytes(), high_bytes);
+        assert_eq!(P_high.as_bytes(), &high_bytes);
+
+        // but the point is the same
+        assert_eq!(P_high, P);
+        assert_eq!(P_high * s, P * s);
+        assert_eq!((P_high * s).to_bytes(), (P * s).to_bytes());
+        assert_eq!(P_high.to_edwards(0), P.to_edwards(0));
+    }
+
+    #[test]
+    fn mask_u_coordinate_clears_only_high_bit() {
+        let mut csprng = rand_core::OsRng;
+        for _ in 0..100 {
+            let mut bytes = [0u8; 32];
+            csprng.fill_bytes(&mut bytes);
+
+            let masked = mask_u_coordinate(bytes);
+            assert_eq!(masked[31] & 0x80, 0);
+            assert_eq!(masked[31] | (bytes[31] & 0x80), bytes[31]);
+            assert_eq!(masked[..31], bytes[..31]);
+
+            assert_eq!(mask_u_coordinate(masked), masked);
+            assert_eq!(MontgomeryPoint(masked), MontgomeryPoint(bytes));
+        }
+
+        assert_eq!(mask_u_coordinate([0xff; 32])[31], 0x7f);
+        assert_eq!(mask_u_coordinate([0xff; 32])[..31], [0xff; 31]);
+    }
+
     /// Returns a random point on the prime-order subgroup
     fn rand_prime_order_point(mut rng: impl RngCore + CryptoRng) -> EdwardsPoint {
         let s: Scalar = Scalar::random(&mut rng);
@@ -548,6 +613,45 @@ mod test {
         }
     }
 
+    /// The ladder also agrees with Edwards multiplication on points with a
+    /// torsion component, since both treat the scalar as an integer.  This
+    /// includes the exceptional points of the birational map: the identity
+    /// and the 2-torsion point \\((0, -1)\\) both have \\(u = 0\\), and so do
+    /// all of their multiples.
+    #[test]
+    fn montgomery_ladder_matches_edwards_scalarmult_with_torsion() {
+        let mut csprng = rand_core::OsRng;
+
+        assert_eq!(
+            EdwardsPoint::identity().to_montgomery(),
+            MontgomeryPoint::identity()
+        );
+        assert_eq!(
+            constants::EIGHT_TORSION[4].to_montgomery(),
+            MontgomeryPoint::identity()
+        );
+
+        for T in &constants::EIGHT_TORSION {
+            for _ in 0..8 {
+                let p_edwards = rand_prime_order_point(csprng) + T;
+                let p_montgomery = p_edwards.to_montgomery();
+
+                let s = Scalar::random(&mut csprng);
+                assert_eq!(s * p_montgomery, (s * p_edwards).to_montgomery());
+
+                let mut bytes = [0u8; 32];
+                csprng.fill_bytes(&mut bytes);
+                assert_eq!(
+                    p_montgomery.mul_clamped(bytes),
+                    p_edwards.mul_clamped(bytes).to_montgomery()
+                );
+            }
+
+            let s = Scalar::random(&mut csprng);
+            assert_eq!(s * T.to_montgomery(), (s * T).to_montgomery());
+        }
+    }
+
     // Tests that, on the prime-order subgroup, MontgomeryPoint::mul_bits_be is the same as
     // multiplying by the Scalar representation of the same bits
     #[test]
@@ -204,6 +204,7 @@ use crate::scalar::Scalar;
 #[cfg(feature = "precomputed-tables")]
 use crate::traits::BasepointTable;
 use crate::traits::Identity;
+use crate::traits::IsIdentity;
 #[cfg(feature = "alloc")]
 use crate::traits::{MultiscalarMul, VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
 
@@ -485,6 +486,31 @@ impl<'de> Deserialize<'de> for CompressedRistretto {
 pub struct RistrettoPoint(pub(crate) EdwardsPoint);
 
 impl RistrettoPoint {
+    /// Attempt to use an `EdwardsPoint` as the representative of a
+    /// `RistrettoPoint`.
+    ///
+    /// The Ristretto group is the quotient \\( 2\mathcal E / \mathcal E\[4\]
+    /// \\), so any point of the even subgroup \\( 2 \mathcal E =
+    /// \mathcal E[\ell] \oplus \mathcal E\[4\] \\) represents a Ristretto
+    /// point, namely its coset \\( P + \mathcal E\[4\] \\).  A point \\(P\\)
+    /// is in the even subgroup exactly when \\( 4 \ell P = 0 \\), i.e. when
+    /// its torsion component has order dividing 4.
+    ///
+    /// # Return
+    ///
+    /// - `Some(point)` if `P` is in the even subgroup;
+    /// - `None` if `P` has a torsion component of order 8.
+    ///
+    /// This is not constant time, which is fine for public points.
+    pub fn from_edwards(P: &EdwardsPoint) -> Option<RistrettoPoint> {
+        let lP = P * constants::BASEPOINT_ORDER_PRIVATE;
+        if lP.mul_by_pow_2(2).is_identity() {
+            Some(RistrettoPoint(*P))
+        } else {
+            None
+        }
+    }
+
     /// Compress this point using the Ristretto encoding.
     pub fn compress(&self) -> CompressedRistretto {
         let mut X = self.0.X;
@@ -576,7 +602,7 @@ impl RistrettoPoint {
                 let XX = P.0.X.square();
                 let YY = P.0.Y.square();
                 let ZZ = P.0.Z.square();
-                let dTT = &P.0.T.square() * &constants::EDWARDS_D;
+                let dTT = P.0.T.square().mul_by_d();
 
                 let e = &P.0.X * &(&P.0.Y + &P.0.Y); // = 2*X*Y
                 let f = &ZZ + &dTT;                  // = Z^2 + d*T^2
@@ -779,6 +805,9 @@ impl RistrettoPoint {
     /// point will be uniformly distributed over the group, and its
     /// discrete log with respect to other points should be unknown.
     ///
+    /// The map is total: every 64-byte input, including ones which are
+    /// not uniformly distributed, produces a valid Ristretto point.
+    ///
     /// # Implementation
     ///
     /// This function splits the input array into two 32-byte halves,
@@ -851,6 +880,10 @@ impl Eq for RistrettoPoint {}
 impl<'a, 'b> Add<&'b RistrettoPoint> for &'a RistrettoPoint {
     type Output = RistrettoPoint;
 
+    /// Add the Edwards representatives.  The even subgroup is closed
+    /// under addition, and adding any elements of \\( \mathcal E\[4\] \\) to
+    /// the inputs only moves the sum within its coset, so this is
+    /// well-defined on the quotient.
     fn add(self, other: &'b RistrettoPoint) -> RistrettoPoint {
         RistrettoPoint(self.0 + other.0)
     }
@@ -1278,7 +1311,9 @@ mod test {
     use super::*;
     use crate::edwards::CompressedEdwardsY;
 
-    use rand_core::OsRng;
+    use crate::traits::ValidityCheck;
+
+    use rand_core::{OsRng, RngCore};
 
     #[test]
     #[cfg(feature = "serde")]
@@ -1479,6 +1514,57 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_edwards() {
+        let mut rng = OsRng;
+        let B = constants::ED25519_BASEPOINT_POINT;
+        let P = B * Scalar::random(&mut rng);
+
+        // Every representative of the coset P + E[4] gives the same point
+        for point in RistrettoPoint(P).coset4() {
+            assert_eq!(
+                RistrettoPoint::from_edwards(&point).unwrap(),
+                RistrettoPoint(P)
+            );
+        }
+        assert_eq!(
+            RistrettoPoint::from_edwards(&EdwardsPoint::identity()).unwrap(),
+            RistrettoPoint::identity()
+        );
+
+        // Points with a torsion component of order 8 are not representatives
+        for i in [1, 3, 5, 7] {
+            let T = constants::EIGHT_TORSION[i];
+            assert!(RistrettoPoint::from_edwards(&T).is_none());
+            assert!(RistrettoPoint::from_edwards(&(P + T)).is_none());
+        }
+
+        // Decompressed points are accepted
+        let Q = RistrettoPoint::random(&mut rng);
+        let Q_decompressed = Q.compress().decompress().unwrap();
+        assert!(RistrettoPoint::from_edwards(&Q_decompressed.0).is_some());
+    }
+
+    #[test]
+    fn add_is_well_defined() {
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let Q = RistrettoPoint::random(&mut rng);
+        let sum = P + Q;
+
+        // The sum is a valid representative, of the expected point
+        assert!(sum.0.is_valid());
+        assert!(RistrettoPoint::from_edwards(&sum.0).is_some());
+        assert_eq!(sum.compress().decompress().unwrap(), sum);
+
+        // Changing the representatives doesn't change the sum
+        for P_rep in P.coset4() {
+            for Q_rep in Q.coset4() {
+                assert_eq!(RistrettoPoint(P_rep) + RistrettoPoint(Q_rep), sum);
+            }
+        }
+    }
+
     #[test]
     fn elligator_vs_ristretto_sage() {
         // Test vectors extracted from ristretto.sage.
@@ -1794,6 +1880,23 @@ mod test {
         }
     }
 
+    #[test]
+    fn one_way_map_output_is_valid() {
+        let mut rng = OsRng;
+        let mut inputs = vec![[0u8; 64], [0xffu8; 64]];
+        for _ in 0..100 {
+            let mut bytes = [0u8; 64];
+            rng.fill_bytes(&mut bytes);
+            inputs.push(bytes);
+        }
+
+        for input in inputs {
+            let P = RistrettoPoint::from_uniform_bytes(&input);
+            assert!(P.0.is_valid());
+            assert_eq!(P.compress().decompress(), Some(P));
+        }
+    }
+
     #[test]
     fn random_roundtrip() {
         let mut rng = OsRng;
@@ -1805,6 +1908,27 @@ mod test {
         }
     }
 
+    /// Test that decompressing then compressing arbitrary bytes is the
+    /// identity whenever decompression succeeds, since Ristretto rejects
+    /// every non-canonical encoding.
+    #[test]
+    fn arbitrary_bytes_roundtrip() {
+        let mut rng = OsRng;
+        let mut decompressed = 0;
+
+        for _ in 0..1000 {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let repr = CompressedRistretto(bytes);
+
+            if let Some(P) = repr.decompress() {
+                assert_eq!(P.compress(), repr);
+                decompressed += 1;
+            }
+        }
+        assert!(decompressed > 0);
+    }
+
     #[test]
     #[cfg(all(feature = "alloc", feature = "rand_core"))]
     fn double_and_compress_1024_random_points() {
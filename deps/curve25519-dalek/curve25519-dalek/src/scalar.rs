@@ -111,6 +111,9 @@
 //! reduces a \\(512\\)-bit integer, if the optional `digest` feature
 //! has been enabled.
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use core::borrow::Borrow;
 use core::fmt::Debug;
 use core::iter::{Product, Sum};
@@ -234,6 +237,11 @@ pub struct Scalar {
 impl Scalar {
     /// Construct a `Scalar` by reducing a 256-bit little-endian integer
     /// modulo the group order \\( \ell \\).
+    ///
+    /// Every input is accepted, including values at or above \\( \ell \\)
+    /// and values with the high bit set, and the result is always reduced.
+    /// Use [`Scalar::from_canonical_bytes`] to reject inputs which are not
+    /// already reduced instead.
     pub fn from_bytes_mod_order(bytes: [u8; 32]) -> Scalar {
         // Temporarily allow s_unreduced.bytes > 2^255 ...
         let s_unreduced = Scalar { bytes };
@@ -247,10 +255,36 @@ impl Scalar {
 
     /// Construct a `Scalar` by reducing a 512-bit little-endian integer
     /// modulo the group order \\( \ell \\).
+    ///
+    /// The result is always reduced.  This is also the way to turn random
+    /// bytes into a scalar: given 64 uniformly random bytes, for example
+    /// from a KDF or from an RNG which does not implement `CryptoRngCore`,
+    /// the result is uniformly distributed for all practical purposes.
+    /// [`Scalar::random`] is exactly this applied to 64 bytes from its RNG.
+    /// Reducing only 32 random bytes with [`Scalar::from_bytes_mod_order`]
+    /// is noticeably biased and should not be used for sampling.
+    ///
+    /// To quantify the bias, write \\( 2\^{512} = q\ell + r \\) with
+    /// \\( 0 < r < \ell \\).  Reduction maps onto every residue, and each
+    /// residue below \\( r \\) has \\( q + 1 \\) preimages while the others
+    /// have \\( q \\).  Counting, the statistical distance from uniform is
+    /// $$
+    ///     \frac{r(\ell - r)}{\ell \cdot 2\^{512}} \leq \frac{\ell}{2\^{514}} < 2\^{-261},
+    /// $$
+    /// using \\( r(\ell - r) \leq \ell\^2 / 4 \\) and \\( \ell < 2\^{253} \\).
     pub fn from_bytes_mod_order_wide(input: &[u8; 64]) -> Scalar {
         UnpackedScalar::from_bytes_wide(input).pack()
     }
 
+    /// Construct a `Scalar` by reducing a 256-bit integer, given as four
+    /// little-endian 64-bit limbs, modulo the group order \\( \ell \\).
+    ///
+    /// This is the same as [`Scalar::from_bytes_mod_order`] applied to
+    /// the little-endian bytes of the limbs.
+    pub fn from_limbs_mod_order(limbs: [u64; 4]) -> Scalar {
+        Scalar::from_bytes_mod_order(write_le_u64s(&limbs))
+    }
+
     /// Attempt to construct a `Scalar` from a canonical byte representation.
     ///
     /// # Return
@@ -264,6 +298,127 @@ impl Scalar {
         CtOption::new(candidate, high_bit_unset & candidate.is_canonical())
     }
 
+    /// Attempt to construct a `Scalar` from a canonical representation as
+    /// four little-endian 64-bit limbs.
+    ///
+    /// This is the inverse of [`Scalar::to_limbs`].  Like
+    /// [`Scalar::from_canonical_bytes`], it returns `None` if the value is
+    /// not less than \\( \ell \\); use [`Scalar::from_limbs_mod_order`] to
+    /// reduce such values instead.
+    pub fn from_canonical_limbs(limbs: [u64; 4]) -> CtOption<Scalar> {
+        Scalar::from_canonical_bytes(write_le_u64s(&limbs))
+    }
+
+    /// Construct a `Scalar` by reducing a 256-bit big-endian integer
+    /// modulo the group order \\( \ell \\).
+    ///
+    /// This is [`Scalar::from_bytes_mod_order`] applied to the reversed
+    /// bytes, for interoperating with encodings that put the most
+    /// significant byte first.
+    pub fn from_bytes_be_mod_order(mut bytes: [u8; 32]) -> Scalar {
+        bytes.reverse();
+        Scalar::from_bytes_mod_order(bytes)
+    }
+
+    /// Attempt to construct a `Scalar` from a canonical big-endian byte
+    /// representation.
+    ///
+    /// This is [`Scalar::from_canonical_bytes`] applied to the reversed
+    /// bytes, so it returns `None` unless the big-endian integer is less
+    /// than \\( \ell \\).
+    pub fn from_canonical_bytes_be(mut bytes: [u8; 32]) -> CtOption<Scalar> {
+        bytes.reverse();
+        Scalar::from_canonical_bytes(bytes)
+    }
+
+    /// Parse a `Scalar` from a string of decimal digits, as found in test
+    /// vectors.
+    ///
+    /// Leading zeros are allowed.  Returns `None` if the string is empty,
+    /// contains anything other than the ASCII digits `0` to `9`, or
+    /// denotes an integer which is not less than \\( \ell \\).
+    ///
+    /// This is not constant time, so it must not be used on secrets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// assert_eq!(Scalar::from_decimal_str("1000"), Some(Scalar::from(1000u64)));
+    /// assert_eq!(Scalar::from_decimal_str("-1"), None);
+    /// ```
+    pub fn from_decimal_str(s: &str) -> Option<Scalar> {
+        if s.is_empty() {
+            return None;
+        }
+
+        let mut limbs = [0u64; 4];
+        for c in s.bytes() {
+            if !c.is_ascii_digit() {
+                return None;
+            }
+            // limbs <- 10 * limbs + digit, failing on overflow past 2^256
+            let mut carry = (c - b'0') as u128;
+            for limb in limbs.iter_mut() {
+                let t = (*limb as u128) * 10 + carry;
+                *limb = t as u64;
+                carry = t >> 64;
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+
+        Scalar::from_canonical_limbs(limbs).into()
+    }
+
+    /// Cheaply check whether `bytes` is certainly not a canonical scalar
+    /// encoding, by looking only at its top three bits.
+    ///
+    /// Since \\( 2\^{252} < \ell < 2\^{253} \\), any encoding with one of
+    /// bits 253 through 255 set is too large.  Bit 252 can't be used, as
+    /// canonical scalars in \\( [2\^{252}, \ell) \\) have it set.
+    ///
+    /// This is only a fast pre-check: a `false` result does **not** mean
+    /// that `bytes` is canonical, since values in \\( [\ell, 2\^{253}) \\)
+    /// are not caught.  Use [`Scalar::from_canonical_bytes`] for the full
+    /// check.
+    pub fn has_noncanonical_high_bits(bytes: &[u8; 32]) -> bool {
+        bytes[31] & 0xe0 != 0
+    }
+
+    /// Decode a length-prefixed sequence of canonical scalars.
+    ///
+    /// The input is a 4-byte little-endian count `n`, followed by exactly
+    /// `n` 32-byte canonical scalar encodings.
+    ///
+    /// # Return
+    ///
+    /// - `Some(scalars)`, with `scalars.len() == n`, if the input has
+    ///   exactly the declared length and every scalar is canonical;
+    /// - `None` if the input is truncated, has trailing bytes, or contains
+    ///   a non-canonical scalar.
+    #[cfg(feature = "alloc")]
+    pub fn decode_length_prefixed(bytes: &[u8]) -> Option<Vec<Scalar>> {
+        if bytes.len() < 4 {
+            return None;
+        }
+        let (prefix, body) = bytes.split_at(4);
+        let n = u32::from_le_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]) as usize;
+        if n.checked_mul(32)? != body.len() {
+            return None;
+        }
+
+        body.chunks_exact(32)
+            .map(|chunk| {
+                let mut s_bytes = [0u8; 32];
+                s_bytes.copy_from_slice(chunk);
+                Option::from(Scalar::from_canonical_bytes(s_bytes))
+            })
+            .collect()
+    }
+
     /// Construct a `Scalar` from the low 255 bits of a 256-bit integer. This breaks the invariant
     /// that scalars are always reduced. Scalar-scalar arithmetic, i.e., addition, subtraction,
     /// multiplication, **does not work** on scalars produced from this function. You may only use
@@ -342,7 +497,8 @@ impl<'a, 'b> Add<&'b Scalar> for &'a Scalar {
     #[allow(non_snake_case)]
     fn add(self, _rhs: &'b Scalar) -> Scalar {
         // The UnpackedScalar::add function produces reduced outputs if the inputs are reduced. By
-        // Scalar invariant #1, this is always the case.
+        // Scalar invariant #2, this is the case except for the unreduced scalars that
+        // `legacy_compatibility` permits.
         UnpackedScalar::add(&self.unpack(), &_rhs.unpack()).pack()
     }
 }
@@ -362,7 +518,8 @@ impl<'a, 'b> Sub<&'b Scalar> for &'a Scalar {
     #[allow(non_snake_case)]
     fn sub(self, rhs: &'b Scalar) -> Scalar {
         // The UnpackedScalar::sub function produces reduced outputs if the inputs are reduced. By
-        // Scalar invariant #1, this is always the case.
+        // Scalar invariant #2, this is the case except for the unreduced scalars that
+        // `legacy_compatibility` permits.
         UnpackedScalar::sub(&self.unpack(), &rhs.unpack()).pack()
     }
 }
@@ -581,7 +738,8 @@ impl Scalar {
     ///
     /// # Returns
     ///
-    /// A random scalar within \\(\mathbb{Z} / \ell\mathbb{Z}\\).
+    /// A random scalar within \\(\mathbb{Z} / \ell\mathbb{Z}\\), obtained by reducing 64 random
+    /// bytes with [`Scalar::from_bytes_mod_order_wide`].
     ///
     /// # Example
     ///
@@ -638,6 +796,10 @@ impl Scalar {
     /// to stream data into the `Digest` than to pass a single byte
     /// slice.
     ///
+    /// The 64 bytes of output are reduced mod \\(\ell\\) exactly as by
+    /// [`Scalar::from_bytes_mod_order_wide`], which callers already
+    /// holding a digest's output bytes can use directly.
+    ///
     /// # Example
     ///
     /// ```
@@ -679,6 +841,10 @@ impl Scalar {
 
     /// Convert this `Scalar` to its underlying sequence of bytes.
     ///
+    /// The bytes are returned by value, so this needs neither `alloc` nor
+    /// `std`.  To write them into an existing buffer, assign the result or
+    /// copy from [`Scalar::as_bytes`].
+    ///
     /// # Example
     ///
     /// ```
@@ -687,6 +853,10 @@ impl Scalar {
     /// let s: Scalar = Scalar::ZERO;
     ///
     /// assert!(s.to_bytes() == [0u8; 32]);
+    ///
+    /// let mut out = [0xffu8; 64];
+    /// out[..32].copy_from_slice(Scalar::ONE.as_bytes());
+    /// assert_eq!(Scalar::from_canonical_bytes(out[..32].try_into().unwrap()).unwrap(), Scalar::ONE);
     /// ```
     pub const fn to_bytes(&self) -> [u8; 32] {
         self.bytes
@@ -707,6 +877,128 @@ impl Scalar {
         &self.bytes
     }
 
+    /// Convert this `Scalar` to its big-endian byte encoding, which is
+    /// [`Scalar::to_bytes`] reversed.
+    ///
+    /// This is the inverse of [`Scalar::from_canonical_bytes_be`].
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let mut bytes = self.bytes;
+        bytes.reverse();
+        bytes
+    }
+
+    /// Convert this `Scalar` to four little-endian 64-bit limbs, so that
+    /// it is the integer \\( \sum\_i \text{limbs}\[i\] 2\^{64 i} \\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(1u128 << 64) + Scalar::from(7u64);
+    ///
+    /// assert_eq!(s.to_limbs(), [7, 1, 0, 0]);
+    /// ```
+    pub fn to_limbs(&self) -> [u64; 4] {
+        let mut limbs = [0u64; 4];
+        read_le_u64_into(&self.bytes, &mut limbs);
+        limbs
+    }
+
+    /// Check, in constant time, whether this `Scalar` is zero mod \\(\ell\\).
+    ///
+    /// The value is reduced before comparing, so an unreduced
+    /// representative such as \\(\ell\\) itself also counts as zero.
+    ///
+    /// This differs from `ff::Field::is_zero`, available with the `group`
+    /// feature, which compares the bytes without reducing them.  The two
+    /// agree on every reduced `Scalar`, and differ only on unreduced ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(7u64);
+    /// assert!(!bool::from(s.is_zero_mod_order()));
+    /// assert!(bool::from((s - s).is_zero_mod_order()));
+    /// ```
+    pub fn is_zero_mod_order(&self) -> Choice {
+        self.reduce().ct_eq(&Scalar::ZERO)
+    }
+
+    /// Check, in constant time, whether the integer representing this
+    /// `Scalar` is less than `bound`.
+    ///
+    /// This is useful for checking that a scalar encodes a small value,
+    /// such as a committed amount less than \\(2\^{64}\\).  Since the
+    /// comparison is with the canonical representative in
+    /// \\([0, \ell)\\), a negative value such as \\(-1\\) is not small.  No
+    /// scalar is less than a `bound` of zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(1000u64);
+    /// assert!(bool::from(s.is_in_range(1001)));
+    /// assert!(!bool::from(s.is_in_range(1000)));
+    /// assert!(!bool::from((-s).is_in_range(u64::MAX)));
+    /// ```
+    pub fn is_in_range(&self, bound: u64) -> Choice {
+        let mut low_bytes = [0u8; 8];
+        low_bytes.copy_from_slice(&self.bytes[..8]);
+        let low = u64::from_le_bytes(low_bytes);
+
+        let high_is_zero = self.bytes[8..].ct_eq(&[0u8; 24]);
+        // low < bound exactly when low - bound borrows
+        let (_, low_is_less) = low.overflowing_sub(bound);
+
+        high_is_zero & Choice::from(low_is_less as u8)
+    }
+
+    /// Multiply this `Scalar` by a small integer `k`.
+    ///
+    /// The result is the same as `self * Scalar::from(k)`.  It is computed
+    /// by double-and-add over the bits of `k`, with one scalar addition
+    /// per bit and one more per set bit.
+    ///
+    /// # Warning
+    ///
+    /// This is variable time in `k`, which must not be secret.  It is
+    /// constant time in `self`.
+    pub fn mul_by_u64(&self, k: u64) -> Scalar {
+        let x = self.unpack();
+        let mut acc = UnpackedScalar::ZERO;
+        for i in (0..(64 - k.leading_zeros())).rev() {
+            acc = UnpackedScalar::add(&acc, &acc);
+            if (k >> i) & 1 == 1 {
+                acc = UnpackedScalar::add(&acc, &x);
+            }
+        }
+        acc.pack()
+    }
+
+    /// Raise this `Scalar` to the power `exp`, using square-and-multiply.
+    ///
+    /// By convention \\(x\^0 = 1\\) for every \\(x\\), including zero.
+    ///
+    /// # Warning
+    ///
+    /// This is variable time in `exp`, which must not be secret.  It is
+    /// constant time in `self`.
+    pub fn pow_u64(&self, exp: u64) -> Scalar {
+        let mut acc = Scalar::ONE;
+        for i in (0..(64 - exp.leading_zeros())).rev() {
+            acc = acc * acc;
+            if (exp >> i) & 1 == 1 {
+                acc *= self;
+            }
+        }
+        acc
+    }
+
     /// Given a nonzero `Scalar`, compute its multiplicative inverse.
     ///
     /// # Warning
@@ -748,6 +1040,32 @@ impl Scalar {
         self.unpack().invert().pack()
     }
 
+    /// Compute the multiplicative inverse of this `Scalar`, if it exists.
+    ///
+    /// Unlike [`Scalar::invert`], this is safe to call on untrusted inputs:
+    /// the zero case is detected in constant time rather than silently
+    /// producing a meaningless result.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(1/self)` if `self` is nonzero;
+    /// - `None` if `self` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let x = Scalar::from(7u64);
+    /// let inv_x = x.invert_ct().unwrap();
+    /// assert_eq!(inv_x * x, Scalar::ONE);
+    ///
+    /// assert!(bool::from(Scalar::ZERO.invert_ct().is_none()));
+    /// ```
+    pub fn invert_ct(&self) -> CtOption<Scalar> {
+        CtOption::new(self.invert(), !self.is_zero_mod_order())
+    }
+
     /// Given a slice of nonzero (possibly secret) `Scalar`s,
     /// compute their inverses in a batch.
     ///
@@ -836,6 +1154,147 @@ impl Scalar {
         ret
     }
 
+    /// Evaluate the polynomial with the given coefficients at `x`.
+    ///
+    /// The coefficients are given in order of increasing degree, so this
+    /// computes
+    /// $$
+    /// \sum\_{i=0}\^{n-1} c\_i x\^i \pmod \ell
+    /// $$
+    /// using Horner's method.  An empty list of coefficients is the zero
+    /// polynomial, and evaluates to zero.
+    ///
+    /// The sequence of operations depends only on the number of
+    /// coefficients, so this is safe to use with secret coefficients or a
+    /// secret evaluation point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// // 3 + 2x + x^2 at x = 5
+    /// let coeffs = [Scalar::from(3u64), Scalar::from(2u64), Scalar::ONE];
+    /// let y = Scalar::eval_polynomial(&coeffs, &Scalar::from(5u64));
+    /// assert_eq!(y, Scalar::from(38u64));
+    /// ```
+    pub fn eval_polynomial(coeffs: &[Scalar], x: &Scalar) -> Scalar {
+        coeffs.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c)
+    }
+
+    /// Compute the inner product \\( \sum\_i a\_i b\_i \\) of two vectors of
+    /// scalars.  The inner product of two empty vectors is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    pub fn inner_product(a: &[Scalar], b: &[Scalar]) -> Scalar {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b.iter()).map(|(a_i, b_i)| a_i * b_i).sum()
+    }
+
+    /// Multiply every element of the vector `v` by `k`.
+    ///
+    /// The result has the same length as `v`, with \\(k v\_i\\) in
+    /// position \\(i\\).
+    #[cfg(feature = "alloc")]
+    pub fn scale_vector(v: &[Scalar], k: &Scalar) -> Vec<Scalar> {
+        v.iter().map(|v_i| k * v_i).collect()
+    }
+
+    /// Compute the first `n` powers \\(1, x, x\^2, \ldots, x\^{n-1}\\) of
+    /// `self`, as used for challenge vectors in argument systems.
+    ///
+    /// The result is empty when `n` is zero.  Note that \\(0\^0 = 1\\), so
+    /// the powers of zero are \\(1, 0, 0, \ldots\\).
+    #[cfg(feature = "alloc")]
+    pub fn powers(&self, n: usize) -> Vec<Scalar> {
+        let mut x_i = Scalar::ONE;
+        (0..n)
+            .map(|_| {
+                let current = x_i;
+                x_i *= self;
+                current
+            })
+            .collect()
+    }
+
+    /// Compute the Lagrange basis coefficient for `indices[i]`, evaluated
+    /// at `x`:
+    /// $$
+    /// \lambda\_i(x) = \prod\_{j \neq i} \frac{x - x\_j}{x\_i - x\_j},
+    /// $$
+    /// where \\(x\_j\\) is `indices[j]`.
+    ///
+    /// This is the coefficient used to interpolate a polynomial from its
+    /// values at `indices`, as in Shamir secret sharing, where `x` is
+    /// usually zero.  It is \\(1\\) when `x` is `indices[i]`, and \\(0\\)
+    /// when `x` is any other index.
+    ///
+    /// # Return
+    ///
+    /// - `Some(coefficient)` if the `indices` are distinct;
+    /// - `None` if any two of the `indices` are equal, whether or not one
+    ///   of them is `indices[i]`, since they do not define an
+    ///   interpolating polynomial.
+    ///
+    /// Checking for repeated indices takes \\(O(n\^2)\\) multiplications
+    /// for \\(n\\) indices.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds for `indices`.
+    pub fn lagrange_coefficient(indices: &[Scalar], i: usize, x: &Scalar) -> CtOption<Scalar> {
+        let x_i = &indices[i];
+        let mut num = Scalar::ONE;
+        let mut den = Scalar::ONE;
+        for (j, x_j) in indices.iter().enumerate() {
+            if j == i {
+                continue;
+            }
+            num *= x - x_j;
+            den *= x_i - x_j;
+        }
+
+        // The indices are distinct exactly when the product of all their
+        // pairwise differences is nonzero.  This includes the factors of
+        // den, so den is nonzero whenever the result is used.
+        let mut differences = Scalar::ONE;
+        for (j, x_j) in indices.iter().enumerate() {
+            for x_k in &indices[j + 1..] {
+                differences *= x_j - x_k;
+            }
+        }
+
+        CtOption::new(num * den.invert(), !differences.is_zero_mod_order())
+    }
+
+    /// Decompose this `Scalar` into its lowest `n` bits, in little-endian
+    /// order, as used by bit-decomposition range proofs.
+    ///
+    /// # Return
+    ///
+    /// - `Some(bits)`, with `bits.len() == n` and
+    ///   \\( \sum\_i \mathtt{bits}\[i\] 2\^i \\) equal to the integer
+    ///   representing this `Scalar`, if that integer is less than \\(2\^n\\);
+    /// - `None` otherwise.
+    ///
+    /// For `n` greater than 256 the result is padded with `false`.
+    ///
+    /// The high bits are checked by folding them together rather than
+    /// stopping at the first set bit, so the running time does not depend on
+    /// the value of the `Scalar`.  The only thing revealed is whether it fits
+    /// in `n` bits, which the return value reveals anyway.
+    #[cfg(feature = "alloc")]
+    pub fn to_bits_le(&self, n: usize) -> Option<Vec<bool>> {
+        let mut bits: Vec<bool> = self.bits_le().collect();
+        let high_bits = bits.iter().skip(n).fold(0u8, |acc, &bit| acc | bit as u8);
+        if high_bits != 0 {
+            return None;
+        }
+        bits.resize(n, false);
+        Some(bits)
+    }
+
     /// Get the bits of the scalar, in little-endian order
     pub(crate) fn bits_le(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
         (0..256).map(|i| {
@@ -918,6 +1377,13 @@ impl Scalar {
     /// If \\( k \mod 2^w\\) is even, we emit \\(0\\), advance 1 bit
     /// and reindex.  In fact, by setting all digits to \\(0\\)
     /// initially, we don't need to emit anything.
+    ///
+    /// The resulting digits satisfy the invariants the NAF lookup tables
+    /// rely on: every nonzero digit is odd with \\( |n\_i| < 2^{w-1} \\),
+    /// and any two nonzero digits are at least \\(w\\) positions apart,
+    /// since we advance \\(w\\) bits after emitting one.  The final
+    /// carry is never lost, because a reduced scalar has \\( k < 2^{253} \\),
+    /// so a carry out of the last nonzero window still fits in 256 digits.
     pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
         // required by the NAF definition
         debug_assert!(w >= 2);
@@ -982,6 +1448,8 @@ impl Scalar {
     /// The largest value that can be decomposed like this is just over \\(2^{255}\\). Thus, in
     /// order to not error, the top bit MUST NOT be set, i.e., `Self` MUST be less than
     /// \\(2^{255}\\).
+    ///
+    /// Every digit therefore lies in \\([-8, 8]\\), the range accepted by `LookupTable::select`.
     pub(crate) fn as_radix_16(&self) -> [i8; 64] {
         debug_assert!(self[31] <= 127);
         let mut output = [0i8; 64];
@@ -1131,6 +1599,11 @@ impl Scalar {
 
     /// Check whether this `Scalar` is the canonical representative mod \\(\ell\\). This is not
     /// public because any `Scalar` that is publicly observed is reduced, by scalar invariant #2.
+    ///
+    /// This is true exactly when the bytes encode an integer less than \\(\ell\\), and false
+    /// for the unreduced scalars which break invariant #2, such as clamped integers.  Code that
+    /// is only correct for reduced inputs can check this with
+    /// `debug_assert!(bool::from(s.is_canonical()))`.
     fn is_canonical(&self) -> Choice {
         self.ct_eq(&self.reduce())
     }
@@ -1229,7 +1702,7 @@ impl Field for Scalar {
     }
 
     fn invert(&self) -> CtOption<Self> {
-        CtOption::new(self.invert(), !self.is_zero())
+        self.invert_ct()
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
@@ -1363,6 +1836,15 @@ fn read_le_u64_into(src: &[u8], dst: &mut [u64]) {
     }
 }
 
+/// Write four u64s as 32 little endian bytes.
+fn write_le_u64s(src: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, val) in bytes.chunks_exact_mut(8).zip(src.iter()) {
+        chunk.copy_from_slice(&val.to_le_bytes());
+    }
+    bytes
+}
+
 /// _Clamps_ the given little-endian representation of a 32-byte integer. Clamping the value puts
 /// it in the range:
 ///
@@ -1532,8 +2014,27 @@ pub(crate) mod test {
         }
     }
 
+    /// Check that the digits of a width-`w` NAF are in range, odd or zero,
+    /// and that nonzero digits are at least `w` positions apart.
+    fn assert_naf_digits_valid(naf: &[i8; 256], w: usize) {
+        let bound = 1i16 << (w - 1);
+        let mut last_nonzero: Option<usize> = None;
+        for (i, &digit) in naf.iter().enumerate() {
+            if digit == 0 {
+                continue;
+            }
+            assert!((digit as i16).abs() < bound, "digit {} out of range", i);
+            assert_eq!(digit & 1, 1, "digit {} is even", i);
+            if let Some(j) = last_nonzero {
+                assert!(i - j >= w, "digits {} and {} are too close", j, i);
+            }
+            last_nonzero = Some(i);
+        }
+    }
+
     fn non_adjacent_form_iter(w: usize, x: &Scalar) {
         let naf = x.non_adjacent_form(w);
+        assert_naf_digits_valid(&naf, w);
 
         // Reconstruct the scalar from the computed NAF
         let mut y = Scalar::ZERO;
@@ -1561,6 +2062,33 @@ pub(crate) mod test {
         }
     }
 
+    #[test]
+    fn non_adjacent_form_digits_valid() {
+        let mut high = [0xffu8; 32];
+        high[31] = 0x0f;
+        let edge_cases = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            BASEPOINT_ORDER_MINUS_ONE,
+            // 2^252 - 1, whose windows are all odd and carry to the top
+            Scalar { bytes: high },
+            -Scalar::from(2u64),
+        ];
+        for x in &edge_cases {
+            for w in 2..=8 {
+                non_adjacent_form_iter(w, x);
+            }
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let x = Scalar::random(&mut rng);
+            for w in 2..=8 {
+                non_adjacent_form_iter(w, &x);
+            }
+        }
+    }
+
     #[test]
     fn from_u64() {
         let val: u64 = 0xdeadbeefdeadbeef;
@@ -1595,6 +2123,53 @@ pub(crate) mod test {
         assert_eq!(Scalar::ZERO - Scalar::ONE, BASEPOINT_ORDER_MINUS_ONE);
     }
 
+    #[test]
+    fn sub_add_inverse() {
+        for a in 0..=u8::MAX {
+            let sa = Scalar::from(a);
+            assert_eq!(sa - sa, Scalar::ZERO);
+
+            for b in 0..=u8::MAX {
+                let sb = Scalar::from(b);
+                let diff = sa - sb;
+                assert_eq!(diff + sb, sa);
+                if a >= b {
+                    assert_eq!(diff, Scalar::from(a - b));
+                } else {
+                    assert_eq!(diff, -Scalar::from(b - a));
+                }
+            }
+        }
+
+        for a in [X, Y, BASEPOINT_ORDER_MINUS_ONE] {
+            assert_eq!(a - a, Scalar::ZERO);
+            for b in [X, Y, Scalar::ONE, BASEPOINT_ORDER_MINUS_ONE] {
+                assert_eq!((a - b) + b, a);
+            }
+        }
+    }
+
+    #[test]
+    fn is_canonical() {
+        for s in [Scalar::ZERO, Scalar::ONE, X, Y, BASEPOINT_ORDER_MINUS_ONE] {
+            assert!(bool::from(s.is_canonical()));
+        }
+
+        let mut l_plus_one = constants::BASEPOINT_ORDER_PRIVATE;
+        l_plus_one.bytes[0] += 1;
+        for s in [
+            constants::BASEPOINT_ORDER_PRIVATE,
+            l_plus_one,
+            LARGEST_UNREDUCED_SCALAR,
+            Scalar { bytes: [0xff; 32] },
+            Scalar {
+                bytes: clamp_integer([0u8; 32]),
+            },
+        ] {
+            assert!(!bool::from(s.is_canonical()));
+        }
+    }
+
     #[test]
     fn impl_add() {
         let two = Scalar::from(2u64);
@@ -1610,6 +2185,29 @@ pub(crate) mod test {
         assert_eq!(should_be_X_times_Y, X_TIMES_Y);
     }
 
+    #[test]
+    fn mul_commutative_and_unital() {
+        for a in 0..=u8::MAX {
+            let sa = Scalar::from(a);
+            assert_eq!(sa * Scalar::ONE, sa);
+            assert_eq!(Scalar::ONE * sa, sa);
+
+            for b in 0..=u8::MAX {
+                let sb = Scalar::from(b);
+                let product = Scalar::from(a as u64 * b as u64);
+                assert_eq!(sa * sb, product);
+                assert_eq!(sb * sa, product);
+            }
+        }
+
+        for a in [X, Y, BASEPOINT_ORDER_MINUS_ONE] {
+            assert_eq!(a * Scalar::ONE, a);
+            for b in [X, Y, BASEPOINT_ORDER_MINUS_ONE] {
+                assert_eq!(a * b, b * a);
+            }
+        }
+    }
+
     #[allow(non_snake_case)]
     #[test]
     #[cfg(feature = "alloc")]
@@ -1718,6 +2316,28 @@ pub(crate) mod test {
         assert_eq!(should_be_one, Scalar::ONE);
     }
 
+    #[allow(non_snake_case)]
+    #[test]
+    fn invert_ct() {
+        let inv_X = X.invert_ct();
+        assert!(bool::from(inv_X.is_some()));
+        assert_eq!(inv_X.unwrap(), XINV);
+
+        assert!(bool::from(Scalar::ZERO.invert_ct().is_none()));
+        assert_eq!(Scalar::ONE.invert_ct().unwrap(), Scalar::ONE);
+        // l is an unreduced representative of zero, so it has no inverse
+        assert!(bool::from(
+            constants::BASEPOINT_ORDER_PRIVATE.invert_ct().is_none()
+        ));
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let x = Scalar::random(&mut rng);
+            let inv_x = x.invert_ct().unwrap();
+            assert_eq!(inv_x * x, Scalar::ONE);
+        }
+    }
+
     // Negating a scalar twice should result in the original scalar.
     #[allow(non_snake_case)]
     #[test]
@@ -1770,6 +2390,227 @@ pub(crate) mod test {
         assert_eq!(montgomery_reduced.0, expected.unpack().0)
     }
 
+    #[test]
+    #[cfg(feature = "digest")]
+    fn from_hash_is_wide_reduction_of_output() {
+        use sha2::{Digest, Sha512};
+
+        let msg = b"To really appreciate architecture, you may even need to commit a murder";
+        let mut output = [0u8; 64];
+        output.copy_from_slice(Sha512::digest(msg).as_slice());
+
+        let expected = Scalar::from_bytes_mod_order_wide(&output);
+        assert_eq!(Scalar::from_hash(Sha512::new_with_prefix(msg)), expected);
+        assert_eq!(Scalar::hash_from_bytes::<Sha512>(msg), expected);
+    }
+
+    /// Check `from_bytes_mod_order` against evaluating the bytes as a
+    /// polynomial in 256 with `Scalar` arithmetic.
+    #[test]
+    fn from_bytes_mod_order_matches_horner() {
+        fn horner(bytes: &[u8; 32]) -> Scalar {
+            let radix = Scalar::from(256u64);
+            bytes
+                .iter()
+                .rev()
+                .fold(Scalar::ZERO, |acc, &b| acc * radix + Scalar::from(b))
+        }
+
+        let mut l_plus_one = constants::BASEPOINT_ORDER_PRIVATE.to_bytes();
+        l_plus_one[0] += 1;
+        let mut rng = rand::thread_rng();
+        let mut random = [[0u8; 32]; 64];
+        for bytes in random.iter_mut() {
+            rng.fill_bytes(bytes);
+        }
+        let fixed = [
+            [0u8; 32],
+            [0xff; 32],
+            constants::BASEPOINT_ORDER_PRIVATE.to_bytes(),
+            l_plus_one,
+            LARGEST_UNREDUCED_SCALAR.to_bytes(),
+        ];
+
+        for bytes in fixed.iter().chain(random.iter()) {
+            let s = Scalar::from_bytes_mod_order(*bytes);
+            assert_eq!(s, horner(bytes));
+            assert!(bool::from(s.is_canonical()));
+        }
+    }
+
+    #[test]
+    fn from_decimal_str() {
+        assert_eq!(Scalar::from_decimal_str("0"), Some(Scalar::ZERO));
+        assert_eq!(Scalar::from_decimal_str("1"), Some(Scalar::ONE));
+        assert_eq!(
+            Scalar::from_decimal_str("000000000000000000000000000000000000000000000000000000000000000000000000000000000042"),
+            Some(Scalar::from(42u64))
+        );
+        assert_eq!(
+            Scalar::from_decimal_str("12345678901234567890123456789"),
+            Some(Scalar::from(0x27e41b3246bec9b16e398115u128))
+        );
+
+        // l - 1 is the largest accepted value
+        assert_eq!(
+            Scalar::from_decimal_str(
+                "7237005577332262213973186563042994240857116359379907606001950938285454250988"
+            ),
+            Some(BASEPOINT_ORDER_MINUS_ONE)
+        );
+        assert_eq!(
+            Scalar::from_decimal_str(
+                "7237005577332262213973186563042994240857116359379907606001950938285454250989"
+            ),
+            None
+        );
+        // 2^256 does not fit in the accumulator
+        assert_eq!(
+            Scalar::from_decimal_str(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            ),
+            None
+        );
+
+        for s in ["", "-1", "+1", " 1", "1 ", "1a", "0x10", "1_000"] {
+            assert_eq!(Scalar::from_decimal_str(s), None, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn from_limbs_mod_order() {
+        // Small values are unchanged
+        assert_eq!(Scalar::from_limbs_mod_order([0; 4]), Scalar::ZERO);
+        assert_eq!(
+            Scalar::from_limbs_mod_order([u64::MAX, 0, 0, 0]),
+            Scalar::from(u64::MAX)
+        );
+        // limbs[1] has weight 2^64
+        assert_eq!(
+            Scalar::from_limbs_mod_order([0, 1, 0, 0]),
+            Scalar::from(1u128 << 64)
+        );
+
+        // l reduces to zero, and l + 1 to one
+        let mut l_limbs = constants::BASEPOINT_ORDER_PRIVATE.to_limbs();
+        assert_eq!(Scalar::from_limbs_mod_order(l_limbs), Scalar::ZERO);
+        l_limbs[0] += 1;
+        assert_eq!(Scalar::from_limbs_mod_order(l_limbs), Scalar::ONE);
+
+        // 2^256 - 1, which is above 2^255
+        assert_eq!(
+            Scalar::from_limbs_mod_order([u64::MAX; 4]),
+            Scalar::from_bytes_mod_order([0xff; 32])
+        );
+        assert_eq!(
+            Scalar::from_limbs_mod_order([u64::MAX; 4]) + Scalar::ONE,
+            Scalar::from(2u64).pow_u64(256)
+        );
+    }
+
+    #[test]
+    fn random_is_wide_reduction_of_rng_bytes() {
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(0x5ca1a5);
+        let mut rng_copy = rng.clone();
+        for _ in 0..16 {
+            let mut bytes = [0u8; 64];
+            rng_copy.fill_bytes(&mut bytes);
+
+            let s = Scalar::random(&mut rng);
+            assert_eq!(s, Scalar::from_bytes_mod_order_wide(&bytes));
+            assert!(bool::from(s.is_canonical()));
+        }
+
+        assert!(bool::from(
+            Scalar::from_bytes_mod_order_wide(&[0xff; 64]).is_canonical()
+        ));
+    }
+
+    #[test]
+    fn wide_reduction_remainder() {
+        // r = 2^512 mod l is one more than the reduction of 2^512 - 1
+        let r = Scalar::from_bytes_mod_order_wide(&[0xff; 64]) + Scalar::ONE;
+        assert_eq!(r, Scalar::from(2u64).pow_u64(512));
+        // so r != 0, and the residues really do split into two classes
+        assert_ne!(r, Scalar::ZERO);
+
+        // l < 2^253, which bounds the statistical distance by 2^-261
+        let l = constants::BASEPOINT_ORDER_PRIVATE.to_bytes();
+        assert!(l[31] < 1 << 5);
+        assert!(l[31] >= 1 << 4);
+    }
+
+    #[test]
+    fn from_bytes_be() {
+        // The most significant byte comes first
+        let mut one_be = [0u8; 32];
+        one_be[31] = 1;
+        assert_eq!(Scalar::from_bytes_be_mod_order(one_be), Scalar::ONE);
+        assert_eq!(
+            Scalar::from_canonical_bytes_be(one_be).unwrap(),
+            Scalar::ONE
+        );
+
+        let mut two_to_the_248 = [0u8; 32];
+        two_to_the_248[0] = 1;
+        assert_eq!(
+            Scalar::from_bytes_be_mod_order(two_to_the_248),
+            Scalar::from(2u64).pow_u64(248)
+        );
+
+        assert_eq!(Scalar::ONE.to_bytes_be(), one_be);
+
+        for s in [X, Y, -Scalar::ONE] {
+            let mut be = s.to_bytes();
+            be.reverse();
+            assert_eq!(s.to_bytes_be(), be);
+            assert_eq!(Scalar::from_bytes_be_mod_order(be), s);
+            assert_eq!(Scalar::from_canonical_bytes_be(be).unwrap(), s);
+        }
+
+        // l is reduced by the lenient decoder and rejected by the strict one
+        let mut l_be = constants::BASEPOINT_ORDER_PRIVATE.to_bytes();
+        l_be.reverse();
+        assert_eq!(Scalar::from_bytes_be_mod_order(l_be), Scalar::ZERO);
+        assert!(bool::from(Scalar::from_canonical_bytes_be(l_be).is_none()));
+
+        assert_eq!(
+            Scalar::from_bytes_be_mod_order([0xff; 32]),
+            Scalar::from_bytes_mod_order([0xff; 32])
+        );
+        assert!(bool::from(
+            Scalar::from_canonical_bytes_be([0xff; 32]).is_none()
+        ));
+    }
+
+    #[test]
+    fn limbs_round_trip() {
+        assert_eq!(Scalar::ZERO.to_limbs(), [0; 4]);
+        assert_eq!(Scalar::ONE.to_limbs(), [1, 0, 0, 0]);
+
+        for s in [X, Y, -Scalar::ONE] {
+            let limbs = s.to_limbs();
+            assert_eq!(Scalar::from_canonical_limbs(limbs).unwrap(), s);
+            assert_eq!(Scalar::from_limbs_mod_order(limbs), s);
+
+            // The limbs are the little-endian words of the byte encoding
+            let mut bytes = [0u8; 32];
+            for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs.iter()) {
+                chunk.copy_from_slice(&limb.to_le_bytes());
+            }
+            assert_eq!(bytes, s.to_bytes());
+        }
+
+        // l and above are rejected, but reduced by from_limbs_mod_order
+        let mut l_limbs = constants::BASEPOINT_ORDER_PRIVATE.to_limbs();
+        assert!(bool::from(Scalar::from_canonical_limbs(l_limbs).is_none()));
+        assert_eq!(Scalar::from_limbs_mod_order(l_limbs), Scalar::ZERO);
+        l_limbs[3] |= 1 << 63;
+        assert!(bool::from(Scalar::from_canonical_limbs(l_limbs).is_none()));
+    }
+
     #[test]
     fn canonical_decoding() {
         // canonical encoding of 1667457891
@@ -1801,6 +2642,185 @@ pub(crate) mod test {
         ));
     }
 
+    /// Test that decoding then encoding arbitrary bytes is the identity
+    /// whenever decoding succeeds.
+    #[test]
+    fn canonical_decoding_round_trip() {
+        let mut rng = rand::thread_rng();
+        let mut decoded = 0;
+
+        for i in 0..1000 {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            // Only about 1 in 16 uniformly random strings is below l, so
+            // clear some of the high bits for most of the inputs
+            if i % 4 != 0 {
+                bytes[31] &= 0x1f;
+            }
+
+            if let Some(s) = Option::<Scalar>::from(Scalar::from_canonical_bytes(bytes)) {
+                assert_eq!(s.to_bytes(), bytes);
+                decoded += 1;
+            }
+        }
+        assert!(decoded > 0);
+
+        // The largest canonical encoding, l - 1
+        let l_minus_one = (-Scalar::ONE).to_bytes();
+        assert_eq!(
+            Scalar::from_canonical_bytes(l_minus_one)
+                .unwrap()
+                .to_bytes(),
+            l_minus_one
+        );
+    }
+
+    #[test]
+    fn is_zero_mod_order() {
+        assert!(bool::from(Scalar::ZERO.is_zero_mod_order()));
+        assert!(!bool::from(Scalar::ONE.is_zero_mod_order()));
+        assert!(!bool::from(X.is_zero_mod_order()));
+        assert!(bool::from((X - X).is_zero_mod_order()));
+        assert!(bool::from((X + (-X)).is_zero_mod_order()));
+
+        // l is an unreduced representative of zero
+        assert!(bool::from(
+            constants::BASEPOINT_ORDER_PRIVATE.is_zero_mod_order()
+        ));
+        // but 2^255 - 1 is not a multiple of l
+        assert!(!bool::from(LARGEST_UNREDUCED_SCALAR.is_zero_mod_order()));
+    }
+
+    #[test]
+    fn is_in_range() {
+        let s = Scalar::from(1000u64);
+        assert!(bool::from(s.is_in_range(1001)));
+        assert!(bool::from(s.is_in_range(u64::MAX)));
+        assert!(!bool::from(s.is_in_range(1000)));
+        assert!(!bool::from(s.is_in_range(0)));
+        assert!(!bool::from(Scalar::ZERO.is_in_range(0)));
+        assert!(bool::from(Scalar::ZERO.is_in_range(1)));
+
+        // 2^64 - 1 is below no u64 bound, and 2^64 is in no u64 range
+        let max = Scalar::from(u64::MAX);
+        assert!(!bool::from(max.is_in_range(u64::MAX)));
+        assert!(!bool::from((max + Scalar::ONE).is_in_range(u64::MAX)));
+
+        // Small values only in the canonical sense
+        assert!(!bool::from((-Scalar::ONE).is_in_range(u64::MAX)));
+        assert!(!bool::from(X.is_in_range(u64::MAX)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_bits_le() {
+        let bits = Scalar::from(0b1011u64).to_bits_le(6).unwrap();
+        assert_eq!(bits, [true, true, false, true, false, false]);
+        assert!(Scalar::from(0b1011u64).to_bits_le(3).is_none());
+        assert_eq!(Scalar::ZERO.to_bits_le(0).unwrap(), Vec::<bool>::new());
+        assert!(Scalar::ONE.to_bits_le(0).is_none());
+
+        // The bits reconstruct the scalar
+        let mut rng = rand::thread_rng();
+        let s = Scalar::from(rng.next_u64());
+        let bits = s.to_bits_le(64).unwrap();
+        let reconstructed = bits.iter().rev().fold(Scalar::ZERO, |acc, &bit| {
+            acc + acc + Scalar::from(bit as u8)
+        });
+        assert_eq!(reconstructed, s);
+
+        // Every scalar fits in 256 bits, and the result is padded beyond
+        assert_eq!(X.to_bits_le(256).unwrap().len(), 256);
+        assert_eq!(X.to_bits_le(300).unwrap().len(), 300);
+        assert!(X.to_bits_le(200).is_none());
+    }
+
+    #[test]
+    fn mul_by_u64() {
+        let mut rng = rand::thread_rng();
+        let mut ks = vec![0u64, 1, 2, 3, 255, 1 << 63, u64::MAX];
+        ks.extend((0..20).map(|_| rng.next_u64()));
+
+        for x in [Scalar::ZERO, Scalar::ONE, X, -Y] {
+            for &k in &ks {
+                assert_eq!(x.mul_by_u64(k), x * Scalar::from(k));
+            }
+        }
+    }
+
+    #[test]
+    fn pow_u64() {
+        assert_eq!(X.pow_u64(0), Scalar::ONE);
+        assert_eq!(Scalar::ZERO.pow_u64(0), Scalar::ONE);
+        assert_eq!(Scalar::ZERO.pow_u64(5), Scalar::ZERO);
+        assert_eq!(X.pow_u64(1), X);
+        assert_eq!(X.pow_u64(2), X * X);
+        assert_eq!(Scalar::from(3u64).pow_u64(40), Scalar::from(3u128.pow(40)));
+
+        // x^(a + b) = x^a x^b, for exponents using the full 64 bits
+        let (a, b) = (u64::MAX / 2 - 12345, u64::MAX / 2 + 999);
+        assert_eq!(X.pow_u64(a) * X.pow_u64(b), X.pow_u64(a + b));
+
+        // Fermat: x^(l-1) = 1, and l - 1 > 2^64, so check via x^(2^64) instead
+        let x_2_64 = (0..64).fold(X, |acc, _| acc * acc);
+        assert_eq!(X.pow_u64(u64::MAX) * X, x_2_64);
+    }
+
+    #[test]
+    fn has_noncanonical_high_bits() {
+        // l - 1 is canonical and has bit 252 set
+        let l_minus_one = -Scalar::ONE;
+        assert_eq!(l_minus_one.as_bytes()[31], 0x10);
+        assert!(!Scalar::has_noncanonical_high_bits(l_minus_one.as_bytes()));
+        assert!(!Scalar::has_noncanonical_high_bits(X.as_bytes()));
+
+        // l itself is not caught by the pre-check, only by the full check
+        let l_bytes = constants::BASEPOINT_ORDER_PRIVATE.to_bytes();
+        assert!(!Scalar::has_noncanonical_high_bits(&l_bytes));
+        assert!(bool::from(Scalar::from_canonical_bytes(l_bytes).is_none()));
+
+        for high_bit in 5..8 {
+            let mut bytes = [0u8; 32];
+            bytes[31] = 1 << high_bit;
+            assert!(Scalar::has_noncanonical_high_bits(&bytes));
+            assert!(bool::from(Scalar::from_canonical_bytes(bytes).is_none()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decode_length_prefixed() {
+        let scalars = [X, Y, Scalar::ZERO];
+        let mut bytes = 3u32.to_le_bytes().to_vec();
+        for s in &scalars {
+            bytes.extend_from_slice(s.as_bytes());
+        }
+        assert_eq!(Scalar::decode_length_prefixed(&bytes).unwrap(), scalars);
+
+        // Empty vector
+        assert_eq!(
+            Scalar::decode_length_prefixed(&[0, 0, 0, 0]).unwrap(),
+            Vec::<Scalar>::new()
+        );
+
+        // Missing or truncated prefix
+        assert!(Scalar::decode_length_prefixed(&[]).is_none());
+        assert!(Scalar::decode_length_prefixed(&[0, 0, 0]).is_none());
+
+        // Fewer scalars than declared, or trailing bytes
+        assert!(Scalar::decode_length_prefixed(&bytes[..bytes.len() - 1]).is_none());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(Scalar::decode_length_prefixed(&trailing).is_none());
+        let mut too_many = bytes.clone();
+        too_many[0] = 4;
+        assert!(Scalar::decode_length_prefixed(&too_many).is_none());
+
+        // A non-canonical scalar in the middle of the stream
+        bytes[4 + 32..4 + 64].copy_from_slice(&[0xff; 32]);
+        assert!(Scalar::decode_length_prefixed(&bytes).is_none());
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serde_bincode_scalar_roundtrip() {
@@ -1855,6 +2875,145 @@ pub(crate) mod test {
         }
     }
 
+    #[test]
+    fn eval_polynomial() {
+        let mut rng = rand::thread_rng();
+        let x = Scalar::random(&mut rng);
+
+        assert_eq!(Scalar::eval_polynomial(&[], &x), Scalar::ZERO);
+        assert_eq!(Scalar::eval_polynomial(&[Y], &x), Y);
+
+        let mut coeffs = [Scalar::ZERO; 8];
+        for c in coeffs.iter_mut() {
+            *c = Scalar::random(&mut rng);
+        }
+        let mut expected = Scalar::ZERO;
+        let mut x_i = Scalar::ONE;
+        for c in &coeffs {
+            expected += c * x_i;
+            x_i *= x;
+        }
+        assert_eq!(Scalar::eval_polynomial(&coeffs, &x), expected);
+
+        // Evaluating at zero gives the constant term
+        assert_eq!(Scalar::eval_polynomial(&coeffs, &Scalar::ZERO), coeffs[0]);
+    }
+
+    #[test]
+    fn inner_product() {
+        assert_eq!(Scalar::inner_product(&[], &[]), Scalar::ZERO);
+
+        let a = [1u64, 2, 3].map(Scalar::from);
+        let b = [4u64, 5, 6].map(Scalar::from);
+        assert_eq!(Scalar::inner_product(&a, &b), Scalar::from(32u64));
+
+        // The sum is reduced mod l
+        let minus_one = -Scalar::ONE;
+        assert_eq!(
+            Scalar::inner_product(&[minus_one, X], &[minus_one, -X]),
+            Scalar::ONE - X * X
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn inner_product_length_mismatch_panics() {
+        Scalar::inner_product(&[X, Y], &[X]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn scale_vector() {
+        assert!(Scalar::scale_vector(&[], &X).is_empty());
+
+        let v = [Scalar::ZERO, Scalar::ONE, X, -Y];
+        let scaled = Scalar::scale_vector(&v, &Y);
+        assert_eq!(scaled, [Scalar::ZERO, Y, X * Y, -(Y * Y)]);
+
+        // Scaling is compatible with the inner product
+        assert_eq!(
+            Scalar::inner_product(&scaled, &v),
+            Y * Scalar::inner_product(&v, &v)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn powers() {
+        assert!(X.powers(0).is_empty());
+        assert_eq!(X.powers(1), [Scalar::ONE]);
+        assert_eq!(
+            Scalar::ZERO.powers(3),
+            [Scalar::ONE, Scalar::ZERO, Scalar::ZERO]
+        );
+
+        let powers = X.powers(16);
+        assert_eq!(powers.len(), 16);
+        assert_eq!(powers[0], Scalar::ONE);
+        for i in 1..16 {
+            assert_eq!(powers[i], powers[i - 1] * X);
+        }
+        assert_eq!(powers[8], X.powers(9)[8]);
+
+        // The powers are the values of the monomials
+        let coeffs = [Y, X, Scalar::ONE];
+        assert_eq!(
+            Scalar::inner_product(&coeffs, &X.powers(3)),
+            Scalar::eval_polynomial(&coeffs, &X)
+        );
+    }
+
+    #[test]
+    fn lagrange_coefficient() {
+        let mut rng = rand::thread_rng();
+        let indices: [Scalar; 5] = [1u64, 2, 3, 7, 11].map(Scalar::from);
+
+        // The basis polynomials are 1 at their own index and 0 elsewhere
+        for i in 0..indices.len() {
+            for (j, x_j) in indices.iter().enumerate() {
+                let expected = if i == j { Scalar::ONE } else { Scalar::ZERO };
+                assert_eq!(
+                    Scalar::lagrange_coefficient(&indices, i, x_j).unwrap(),
+                    expected
+                );
+            }
+        }
+
+        // Interpolating a degree-4 polynomial from 5 points recovers its
+        // value everywhere, and in particular its constant term at zero
+        let mut coeffs = [Scalar::ZERO; 5];
+        for c in coeffs.iter_mut() {
+            *c = Scalar::random(&mut rng);
+        }
+        let x = Scalar::random(&mut rng);
+        for point in [Scalar::ZERO, x] {
+            let interpolated: Scalar = indices
+                .iter()
+                .enumerate()
+                .map(|(i, x_i)| {
+                    Scalar::eval_polynomial(&coeffs, x_i)
+                        * Scalar::lagrange_coefficient(&indices, i, &point).unwrap()
+                })
+                .sum();
+            assert_eq!(interpolated, Scalar::eval_polynomial(&coeffs, &point));
+        }
+
+        // A single index gives the constant polynomial 1
+        assert_eq!(
+            Scalar::lagrange_coefficient(&[X], 0, &Y).unwrap(),
+            Scalar::ONE
+        );
+
+        // Repeated indices give no coefficient, for every i, including
+        // those whose own index is not repeated
+        let repeated: [Scalar; 4] = [1u64, 2, 3, 2].map(Scalar::from);
+        for i in 0..repeated.len() {
+            assert!(bool::from(
+                Scalar::lagrange_coefficient(&repeated, i, &Scalar::ZERO).is_none()
+            ));
+        }
+    }
+
     #[cfg(feature = "precomputed-tables")]
     fn test_pippenger_radix_iter(scalar: Scalar, w: usize) {
         let digits_count = Scalar::to_radix_2w_size_hint(w);
@@ -1879,6 +3038,67 @@ pub(crate) mod test {
         assert_eq!(recovered_scalar, scalar.reduce());
     }
 
+    #[test]
+    fn as_radix_16_digit_bounds() {
+        let mut rng = rand::thread_rng();
+        let mut scalars = [Scalar::ZERO; 24];
+        for s in scalars.iter_mut() {
+            *s = Scalar::random(&mut rng);
+        }
+        // Every nibble but the top one is 8, so every digit carries
+        let mut eights = [0x88; 32];
+        eights[31] = 0x78;
+        let edge_cases = [
+            Scalar::ZERO,
+            BASEPOINT_ORDER_MINUS_ONE,
+            Scalar { bytes: eights },
+            // The top digit reaches 8
+            LARGEST_UNREDUCED_SCALAR,
+        ];
+
+        for x in edge_cases.iter().chain(scalars.iter()) {
+            let digits = x.as_radix_16();
+            for (i, &digit) in digits[..63].iter().enumerate() {
+                assert!((-8..8).contains(&digit), "digit {} is {}", i, digit);
+            }
+            assert!((-8..=8).contains(&digits[63]));
+
+            // The recentering carries preserve the value
+            let recovered = digits.iter().rev().fold(Scalar::ZERO, |acc, &digit| {
+                let d = Scalar::from(digit.unsigned_abs());
+                acc * Scalar::from(16u8) + if digit < 0 { -d } else { d }
+            });
+            assert_eq!(recovered, x.reduce());
+        }
+
+        assert_eq!(LARGEST_UNREDUCED_SCALAR.as_radix_16()[63], 8);
+    }
+
+    /// Check that `as_radix_16` reconstructs small scalars exactly, as
+    /// integers rather than mod l.
+    #[test]
+    fn as_radix_16_reconstructs_small_values() {
+        fn reconstruct(digits: &[i8; 64]) -> i128 {
+            // 16^31 = 2^124 still fits in an i128, and the digits of a
+            // value below 2^64 are zero from index 17 on.
+            assert!(digits[31..].iter().all(|&d| d == 0));
+            digits[..31]
+                .iter()
+                .rev()
+                .fold(0i128, |acc, &d| acc * 16 + d as i128)
+        }
+
+        let mut rng = rand::thread_rng();
+        let values = (0..=u8::MAX as u64)
+            .chain([0x8888_8888, u32::MAX as u64, 1 << 63, u64::MAX])
+            .chain((0..64).map(|_| rng.next_u64()));
+
+        for v in values {
+            let digits = Scalar::from(v).as_radix_16();
+            assert_eq!(reconstruct(&digits), v as i128);
+        }
+    }
+
     #[test]
     #[cfg(feature = "precomputed-tables")]
     fn test_pippenger_radix() {
@@ -1923,6 +3143,38 @@ pub(crate) mod test {
         }
     }
 
+    #[test]
+    fn le_u64_round_trip() {
+        // The top bits of each word, and of the final byte, must survive
+        let cases = [
+            [0u64; 4],
+            [u64::MAX; 4],
+            [1 << 63, 1 << 63, 1 << 63, 1 << 63],
+            [
+                0x8000_0000_0000_0001,
+                0xF00F_F11F_0110_EFFE,
+                1,
+                0xFF00_0000_0000_0000,
+            ],
+        ];
+
+        for limbs in cases {
+            let bytes = write_le_u64s(&limbs);
+            let mut read = [0u64; 4];
+            read_le_u64_into(&bytes, &mut read);
+            assert_eq!(read, limbs);
+
+            assert_eq!(bytes[7], (limbs[0] >> 56) as u8);
+            assert_eq!(bytes[31], (limbs[3] >> 56) as u8);
+        }
+
+        let bytes = [0xffu8; 32];
+        let mut limbs = [0u64; 4];
+        read_le_u64_into(&bytes, &mut limbs);
+        assert_eq!(limbs, [u64::MAX; 4]);
+        assert_eq!(write_le_u64s(&limbs), bytes);
+    }
+
     // Tests consistency of From<{integer}> impls for Scalar
     #[test]
     fn test_scalar_from_int() {
@@ -2002,6 +3254,28 @@ pub(crate) mod test {
         assert!(bool::from(Scalar::from_repr([0xff; 32]).is_none()));
     }
 
+    #[cfg(feature = "group")]
+    #[test]
+    fn is_zero_mod_order_vs_ff_is_zero() {
+        for s in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            X,
+            X - X,
+            BASEPOINT_ORDER_MINUS_ONE,
+        ] {
+            assert_eq!(
+                bool::from(s.is_zero_mod_order()),
+                bool::from(Field::is_zero(&s))
+            );
+        }
+
+        // Only is_zero_mod_order reduces first
+        let l = constants::BASEPOINT_ORDER_PRIVATE;
+        assert!(bool::from(l.is_zero_mod_order()));
+        assert!(!bool::from(Field::is_zero(&l)));
+    }
+
     #[test]
     #[should_panic]
     fn test_read_le_u64_into_should_panic_on_bad_input() {
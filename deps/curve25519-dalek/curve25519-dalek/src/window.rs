@@ -31,6 +31,20 @@ use crate::edwards::EdwardsPoint;
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+/// Split a signed digit \\(x\\) into \\(|x|\\) and the sign of \\(x\\), in
+/// constant time.
+///
+/// The sign is set exactly when \\(x < 0\\), so zero counts as positive.
+/// The absolute value is computed in an `i16`, so it is exact for every
+/// `i8`, including \\(-128\\).
+fn digit_abs_and_sign(x: i8) -> (u16, Choice) {
+    // xmask is -1 if x < 0, and 0 otherwise
+    let xmask = x as i16 >> 7;
+    let xabs = (x as i16 + xmask) ^ xmask;
+
+    (xabs as u16, Choice::from((xmask & 1) as u8))
+}
+
 macro_rules! impl_lookup_table {
     (Name = $name:ident, Size = $size:expr, SizeNeg = $neg:expr, SizeRange = $range:expr, ConversionRange = $conv_range:expr) => {
         /// A lookup table of precomputed multiples of a point \\(P\\), used to
@@ -55,20 +69,17 @@ macro_rules! impl_lookup_table {
                 debug_assert!(x >= $neg);
                 debug_assert!(x as i16 <= $size as i16); // XXX We have to convert to i16s here for the radix-256 case.. this is wrong.
 
-                // Compute xabs = |x|
-                let xmask = x as i16 >> 7;
-                let xabs = (x as i16 + xmask) ^ xmask;
+                let (xabs, neg_mask) = digit_abs_and_sign(x);
 
                 // Set t = 0 * P = identity
                 let mut t = T::identity();
                 for j in $range {
                     // Copy `points[j-1] == j*P` onto `t` in constant time if `|x| == j`.
-                    let c = (xabs as u16).ct_eq(&(j as u16));
+                    let c = xabs.ct_eq(&(j as u16));
                     t.conditional_assign(&self.0[j - 1], c);
                 }
                 // Now t == |x| * P.
 
-                let neg_mask = Choice::from((xmask & 1) as u8);
                 t.conditional_negate(neg_mask);
                 // Now t == x * P.
 
@@ -274,3 +285,40 @@ impl<'a> From<&'a EdwardsPoint> for NafLookupTable8<AffineNielsPoint> {
         NafLookupTable8(Ai)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants;
+    use crate::scalar::Scalar;
+
+    #[test]
+    fn digit_abs_and_sign_all_digits() {
+        for x in i8::MIN..=i8::MAX {
+            let (xabs, sign) = digit_abs_and_sign(x);
+            assert_eq!(xabs, x.unsigned_abs() as u16);
+            assert_eq!(bool::from(sign), x < 0);
+        }
+
+        let (xabs, sign) = digit_abs_and_sign(0);
+        assert_eq!((xabs, bool::from(sign)), (0, false));
+        let (xabs, sign) = digit_abs_and_sign(-8);
+        assert_eq!((xabs, bool::from(sign)), (8, true));
+        let (xabs, sign) = digit_abs_and_sign(8);
+        assert_eq!((xabs, bool::from(sign)), (8, false));
+    }
+
+    /// Test that every digit selects the right multiple from the table
+    #[test]
+    fn lookup_table_select() {
+        let P = constants::ED25519_BASEPOINT_POINT * Scalar::from(1234567u64);
+        let table = LookupTable::<ProjectiveNielsPoint>::from(&P);
+
+        for x in -8i8..=8 {
+            let selected = (&EdwardsPoint::identity() + &table.select(x)).as_extended();
+            let x_abs = Scalar::from(x.unsigned_abs());
+            let expected = if x < 0 { -(x_abs * P) } else { x_abs * P };
+            assert_eq!(selected, expected);
+        }
+    }
+}
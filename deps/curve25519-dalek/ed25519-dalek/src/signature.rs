@@ -95,6 +95,24 @@ fn check_scalar(bytes: [u8; 32]) -> Result<Scalar, SignatureError> {
     }
 }
 
+/// Checks the `R` component of a signature.
+///
+/// If `reject_identity` is set, the canonical encoding of the identity point
+/// is rejected; see [`VerifyingKey::verify_with_identity_r_policy`].  Only
+/// that exact encoding is matched here; rejecting other small-order points
+/// requires decompressing `R`, as [`VerifyingKey::verify_strict`] does.
+///
+/// [`VerifyingKey::verify_with_identity_r_policy`]: crate::VerifyingKey::verify_with_identity_r_policy
+/// [`VerifyingKey::verify_strict`]: crate::VerifyingKey::verify_strict
+#[inline(always)]
+#[allow(non_snake_case)]
+pub(crate) fn check_r(R: &CompressedEdwardsY, reject_identity: bool) -> Result<(), SignatureError> {
+    if reject_identity && R.is_identity_encoding() {
+        return Err(InternalError::Verify.into());
+    }
+    Ok(())
+}
+
 impl InternalSignature {
     /// Construct a `Signature` from a slice of bytes.
     ///
@@ -38,7 +38,7 @@ use crate::{
     constants::PUBLIC_KEY_LENGTH,
     errors::{InternalError, SignatureError},
     hazmat::ExpandedSecretKey,
-    signature::InternalSignature,
+    signature::{check_r, InternalSignature},
     signing::SigningKey,
 };
 
@@ -486,6 +486,34 @@ impl VerifyingKey {
         }
     }
 
+    /// Verify a `signature` on a `message`, optionally rejecting a signature
+    /// whose `R` component is the encoding of the identity point.
+    ///
+    /// With `reject_identity_r` unset this is exactly the check performed by
+    /// [`Verifier::verify`].  With it set, a signature whose `R` is the
+    /// canonical encoding of the identity is rejected before anything else is
+    /// computed, as some strict variants of Ed25519 require.  Any signer can
+    /// produce such a signature for its own key, since `R = [s]B - [k]A` is
+    /// the identity whenever `s = k * a`.
+    ///
+    /// This is weaker than [`Self::verify_strict`], which additionally rejects
+    /// every other small-order `R` and weak verifying keys.
+    ///
+    /// # Return
+    ///
+    /// Returns `Ok(())` if the signature is valid, and `Err` otherwise.
+    pub fn verify_with_identity_r_policy(
+        &self,
+        message: &[u8],
+        signature: &ed25519::Signature,
+        reject_identity_r: bool,
+    ) -> Result<(), SignatureError> {
+        let internal = InternalSignature::try_from(signature)?;
+        check_r(&internal.R, reject_identity_r)?;
+
+        self.raw_verify::<Sha512>(message, signature)
+    }
+
     /// Convert this verifying key into Montgomery form.
     ///
     /// This can be used for performing X25519 Diffie-Hellman using Ed25519 keys. The output of
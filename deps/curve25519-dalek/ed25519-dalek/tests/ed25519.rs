@@ -25,7 +25,7 @@ mod vectors {
         constants::ED25519_BASEPOINT_POINT,
         edwards::{CompressedEdwardsY, EdwardsPoint},
         scalar::Scalar,
-        traits::IsIdentity,
+        traits::{Identity, IsIdentity},
     };
 
     #[cfg(not(feature = "digest"))]
@@ -283,6 +283,40 @@ mod vectors {
             .verify_prehashed_strict(message2.clone(), context_str, &sig)
             .is_err());
     }
+
+    // A signer can make R the identity for its own, non-weak key by setting
+    // s = k * a.  verify() accepts such a signature; the identity R policy
+    // and verify_strict() reject it.
+    #[test]
+    fn identity_r_policy() {
+        let signing_key = SigningKey::from_bytes(&[0x42; 32]);
+        let vk = signing_key.verifying_key();
+        assert!(!vk.is_weak());
+
+        let identity = EdwardsPoint::identity();
+        for message in [&b"Send 100 USD to Alice"[..], b"anything else"] {
+            let k = compute_challenge(message, &vk.to_edwards(), &identity, None);
+            let s = k * signing_key.to_scalar();
+            let signature = serialize_signature(&identity, &s);
+            let sig = Signature::try_from(&signature[..]).unwrap();
+
+            assert!(vk.verify(message, &sig).is_ok());
+            assert!(vk
+                .verify_with_identity_r_policy(message, &sig, false)
+                .is_ok());
+            assert!(vk
+                .verify_with_identity_r_policy(message, &sig, true)
+                .is_err());
+            assert!(vk.verify_strict(message, &sig).is_err());
+        }
+
+        // An honest signature is unaffected by the policy.
+        let message = b"Send 100 USD to Alice";
+        let sig = signing_key.sign(message);
+        assert!(vk
+            .verify_with_identity_r_policy(message, &sig, true)
+            .is_ok());
+    }
 }
 
 #[cfg(feature = "rand_core")]
@@ -365,10 +365,38 @@ impl AsRef<[u8]> for SharedSecret {
 ///
 /// assert_eq!(alice_shared, bob_shared);
 /// ```
+///
+/// # Low-order points
+///
+/// The scalar `k` is clamped before use, so it is always a multiple of the
+/// cofactor.  If `u` is a point of small order (or the identity, `u = 0`),
+/// the result is therefore the all-zero encoding of the identity.  RFC7748
+/// permits this, but protocols which require contributory behaviour should
+/// reject it, e.g. with [`SharedSecret::was_contributory`].
 pub fn x25519(k: [u8; 32], u: [u8; 32]) -> [u8; 32] {
     MontgomeryPoint(u).mul_clamped(k).to_bytes()
 }
 
+/// The bare, byte-oriented x25519 function, rejecting non-contributory results.
+///
+/// This computes the same value as [`x25519`], but returns `None` if the
+/// result is the all-zero encoding of the identity, i.e. if `u` was a point of
+/// small order.  See [`SharedSecret::was_contributory`] for a discussion of
+/// when this check is needed.
+///
+/// # Returns
+///
+/// - `Some(x25519(k, u))` if the result is nonzero;
+/// - `None` if the result is all zeros.
+pub fn x25519_checked(k: [u8; 32], u: [u8; 32]) -> Option<[u8; 32]> {
+    let shared = SharedSecret(MontgomeryPoint(u).mul_clamped(k));
+    if shared.was_contributory() {
+        Some(shared.to_bytes())
+    } else {
+        None
+    }
+}
+
 /// The X25519 basepoint, for use with the bare, byte-oriented x25519
 /// function.  This is provided for people who cannot use the typed
 /// DH API for some reason.
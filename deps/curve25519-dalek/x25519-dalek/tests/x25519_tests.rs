@@ -115,6 +115,63 @@ fn rfc7748_ladder_test1_vectorset2() {
     do_rfc7748_ladder_test1(input_scalar, input_point, expected);
 }
 
+/// u-coordinates of points of small order, as encoded on the wire.
+const LOW_ORDER_POINTS: [[u8; 32]; 5] = [
+    // 0
+    [0; 32],
+    // 1
+    [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    // order 8
+    [
+        0xe0, 0xeb, 0x7a, 0x7c, 0x3b, 0x41, 0xb8, 0xae, 0x16, 0x56, 0xe3, 0xfa, 0xf1, 0x9f, 0xc4,
+        0x6a, 0xda, 0x09, 0x8d, 0xeb, 0x9c, 0x32, 0xb1, 0xfd, 0x86, 0x62, 0x05, 0x16, 0x5f, 0x49,
+        0xb8, 0x00,
+    ],
+    // order 8
+    [
+        0x5f, 0x9c, 0x95, 0xbc, 0xa3, 0x50, 0x8c, 0x24, 0xb1, 0xd0, 0xb1, 0x55, 0x9c, 0x83, 0xef,
+        0x5b, 0x04, 0x44, 0x5c, 0xc4, 0x58, 0x1c, 0x8e, 0x86, 0xd8, 0x22, 0x4e, 0xdd, 0xd0, 0x9f,
+        0x11, 0x57,
+    ],
+    // p + 1, a non-canonical encoding of 1
+    [
+        0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+];
+
+#[test]
+fn low_order_points_give_all_zero_output() {
+    let mut scalar_bytes = [0x37; 32];
+
+    for i in 0..32 {
+        scalar_bytes[i] += 2;
+
+        for u in LOW_ORDER_POINTS {
+            assert_eq!(x25519(scalar_bytes, u), [0u8; 32]);
+        }
+    }
+}
+
+#[test]
+fn x25519_checked_rejects_low_order_points() {
+    let scalar_bytes = [0x37; 32];
+
+    for u in LOW_ORDER_POINTS {
+        assert_eq!(x25519_checked(scalar_bytes, u), None);
+    }
+
+    assert_eq!(
+        x25519_checked(scalar_bytes, X25519_BASEPOINT_BYTES),
+        Some(x25519(scalar_bytes, X25519_BASEPOINT_BYTES))
+    );
+}
+
 #[test]
 #[ignore] // Run only if you want to burn a lot of CPU doing 1,000,000 DH operations
 fn rfc7748_ladder_test2() {